@@ -0,0 +1,87 @@
+use crate::{layout::Placement, window::WindowGeometry};
+
+/// Interactive transition between the current window geometry and the result
+/// of a layout, driven by a progress value.
+///
+/// The origin is captured when the gesture begins, so the placements for a
+/// given progress are always the same no matter how the progress got there.
+#[derive(Clone, Debug)]
+pub struct Gesture {
+    /// Origin and target placement of each window.
+    windows: Vec<(Placement, Placement)>,
+    progress: f32,
+}
+
+impl Gesture {
+    /// Starts a gesture from the current geometry of `windows` towards
+    /// `target`. Windows without a target stay where they are.
+    pub fn begin(windows: &[WindowGeometry], target: &[Placement]) -> Self {
+        let windows = windows
+            .iter()
+            .map(|window| {
+                let origin = Placement::new(window.id, window.x, window.y, window.scale);
+                let target = target
+                    .iter()
+                    .find(|placement| placement.id == window.id)
+                    .copied()
+                    .unwrap_or(origin);
+                (origin, target)
+            })
+            .collect();
+        Gesture {
+            windows,
+            progress: 0.0,
+        }
+    }
+
+    pub fn progress(&self) -> f32 {
+        self.progress
+    }
+
+    /// Moves the gesture to `progress`, clamped to `0.0..=1.0`, and returns
+    /// the placements for it.
+    pub fn update(&mut self, progress: f32) -> Vec<Placement> {
+        self.progress = progress.clamp(0.0, 1.0);
+        self.placements()
+    }
+
+    /// Placements at the current progress.
+    pub fn placements(&self) -> Vec<Placement> {
+        let t = self.progress;
+        self.windows
+            .iter()
+            .map(|(origin, target)| Placement {
+                page: target.page,
+                ..Placement::new(
+                    origin.id,
+                    interpolate(origin.x, target.x, t),
+                    interpolate(origin.y, target.y, t),
+                    interpolate(origin.scale, target.scale, t),
+                )
+            })
+            .collect()
+    }
+
+    /// Completes the gesture, returning the placements at progress 1.0.
+    pub fn commit(mut self) -> Vec<Placement> {
+        self.update(1.0)
+    }
+
+    /// Aborts the gesture, returning the placements at progress 0.0.
+    pub fn cancel(mut self) -> Vec<Placement> {
+        self.update(0.0)
+    }
+
+    /// Commits the gesture if it went past half way, cancels it otherwise.
+    pub fn finish(self) -> Vec<Placement> {
+        if self.progress >= 0.5 {
+            self.commit()
+        } else {
+            self.cancel()
+        }
+    }
+}
+
+fn interpolate(from: f32, to: f32, t: f32) -> f32 {
+    from + (to - from) * t
+}
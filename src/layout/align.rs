@@ -0,0 +1,109 @@
+use super::{LayoutOptions, Placement, Rect};
+use crate::window::WindowGeometry;
+
+/// Area covered by each placement and its caption, in the same order.
+fn placed_rects(
+    placements: &[Placement],
+    windows: &[WindowGeometry],
+    caption_height: f32,
+) -> Vec<Rect> {
+    placements
+        .iter()
+        .map(|placement| {
+            let (width, height) = windows
+                .iter()
+                .find(|window| window.id == placement.id)
+                .map_or((0.0, 0.0), |window| (window.width, window.height));
+            Rect::new(
+                placement.x,
+                placement.y,
+                width * placement.scale,
+                height * placement.scale + caption_height,
+            )
+        })
+        .collect()
+}
+
+fn bounds(rects: &[Rect]) -> Rect {
+    let min_x = rects.iter().map(|r| r.x).fold(f32::MAX, f32::min);
+    let min_y = rects.iter().map(|r| r.y).fold(f32::MAX, f32::min);
+    let max_x = rects.iter().map(|r| r.x + r.width).fold(f32::MIN, f32::max);
+    let max_y = rects
+        .iter()
+        .map(|r| r.y + r.height)
+        .fold(f32::MIN, f32::max);
+    Rect::new(min_x, min_y, max_x - min_x, max_y - min_y)
+}
+
+/// Moves the placements so their bounding box is centered in the output.
+pub(crate) fn center(
+    placements: &mut [Placement],
+    windows: &[WindowGeometry],
+    output: Rect,
+    options: &LayoutOptions,
+) {
+    if placements.is_empty() {
+        return;
+    }
+    let bounds = bounds(&placed_rects(placements, windows, options.caption_height));
+    let dx = output.x + (output.width - bounds.width) * 0.5 - bounds.x;
+    let dy = output.y + (output.height - bounds.height) * 0.5 - bounds.y;
+    for placement in placements.iter_mut() {
+        placement.x += dx;
+        placement.y += dy;
+    }
+}
+
+/// Groups the placements in rows of vertically overlapping rects, then
+/// spreads the rows with equal gaps over the output height and centers each
+/// row horizontally. Rows stay at least `spacing` apart, the outer gaps
+/// shrinking first.
+pub(crate) fn balance(
+    placements: &mut [Placement],
+    windows: &[WindowGeometry],
+    output: Rect,
+    options: &LayoutOptions,
+) {
+    if placements.is_empty() {
+        return;
+    }
+    let rects = placed_rects(placements, windows, options.caption_height);
+    let mut order: Vec<usize> = (0..rects.len()).collect();
+    order.sort_by(|a, b| rects[*a].y.total_cmp(&rects[*b].y));
+
+    let mut rows: Vec<Vec<usize>> = Vec::new();
+    let mut row_bottom = f32::MIN;
+    for index in order {
+        let rect = rects[index];
+        match rows.last_mut() {
+            Some(row) if rect.y < row_bottom => row.push(index),
+            _ => rows.push(vec![index]),
+        }
+        row_bottom = row_bottom.max(rect.y + rect.height);
+    }
+
+    let row_bounds: Vec<Rect> = rows
+        .iter()
+        .map(|row| bounds(&row.iter().map(|index| rects[*index]).collect::<Vec<_>>()))
+        .collect();
+    let total_height: f32 = row_bounds.iter().map(|bounds| bounds.height).sum();
+    let free = (output.height - total_height).max(0.0);
+    let gap = if rows.len() > 1 {
+        (free / (rows.len() + 1) as f32)
+            .max(options.spacing)
+            .min(free / (rows.len() - 1) as f32)
+    } else {
+        0.0
+    };
+
+    let mut y = output.y + (free - gap * (rows.len() - 1) as f32) * 0.5;
+    for (row, bounds) in rows.iter().zip(row_bounds) {
+        let dx = output.x + (output.width - bounds.width) * 0.5 - bounds.x;
+        let dy = y - bounds.y;
+        for index in row {
+            placements[*index].x += dx;
+            placements[*index].y += dy;
+        }
+        y += bounds.height + gap;
+    }
+}
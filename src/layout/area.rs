@@ -0,0 +1,153 @@
+use super::Rect;
+use crate::packer::Bin;
+
+/// Tolerance on the usable rect edges and reserved rectangles, for rounding
+/// errors.
+const EPSILON: f32 = 0.01;
+
+/// Space kept free along each edge of an output, e.g. for a top panel.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Insets {
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+    pub left: f32,
+}
+
+/// Part of an output the windows are laid out in: the output rect minus its
+/// insets, with holes where windows must not go (a dock, a notch).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LayoutArea {
+    pub rect: Rect,
+    pub insets: Insets,
+    /// Rectangles no window may overlap, in the same coordinates as `rect`.
+    pub reserved: Vec<Rect>,
+}
+
+impl From<Rect> for LayoutArea {
+    fn from(rect: Rect) -> Self {
+        LayoutArea::new(rect)
+    }
+}
+
+impl LayoutArea {
+    pub fn new(rect: Rect) -> Self {
+        LayoutArea {
+            rect,
+            insets: Insets::default(),
+            reserved: Vec::new(),
+        }
+    }
+
+    /// `rect` shrunk by the insets.
+    pub fn usable(&self) -> Rect {
+        let insets = self.insets;
+        Rect::new(
+            self.rect.x + insets.left,
+            self.rect.y + insets.top,
+            (self.rect.width - insets.left - insets.right).max(0.0),
+            (self.rect.height - insets.top - insets.bottom).max(0.0),
+        )
+    }
+
+    /// Empty bin the size of the usable rect, the reserved rectangles already
+    /// occupied. Its coordinates are relative to the usable rect origin.
+    ///
+    /// The bin is `spacing` larger on the right and bottom, so items padded by
+    /// `spacing` on those sides end up `spacing` apart and still reach the
+    /// edges.
+    pub fn bin(&self, spacing: f32) -> Bin {
+        let usable = self.usable();
+        self.bin_in(Rect::new(
+            usable.x,
+            usable.y,
+            usable.width + spacing,
+            usable.height + spacing,
+        ))
+    }
+
+    fn bin_in(&self, rect: Rect) -> Bin {
+        let mut bin = Bin::new(rect.width, rect.height);
+        for reserved in &self.reserved {
+            bin.reserve(Rect::new(
+                reserved.x - rect.x,
+                reserved.y - rect.y,
+                reserved.width,
+                reserved.height,
+            ));
+        }
+        bin
+    }
+
+    /// Parts of `rect` not covered by a reserved rectangle, disjoint from
+    /// each other.
+    pub fn free_rects_in(&self, rect: Rect) -> Vec<Rect> {
+        self.bin_in(rect)
+            .free()
+            .iter()
+            .map(|free| Rect::new(rect.x + free.x, rect.y + free.y, free.width, free.height))
+            .collect()
+    }
+
+    /// Parts of the usable rect not covered by a reserved rectangle.
+    pub fn free_rects(&self) -> Vec<Rect> {
+        self.free_rects_in(self.usable())
+    }
+
+    /// Largest of the [`free_rects`](Self::free_rects), for strategies that
+    /// need a single rectangle free of reserved space. Falls back to the
+    /// usable rect if it is entirely reserved.
+    pub fn largest_free_rect(&self) -> Rect {
+        self.free_rects()
+            .into_iter()
+            .max_by(|a, b| (a.width * a.height).total_cmp(&(b.width * b.height)))
+            .unwrap_or_else(|| self.usable())
+    }
+
+    /// The area extended downwards to `height`, e.g. to scroll through it.
+    /// Reserved rectangles along the bottom edge, like a dock, are moved to
+    /// the new bottom edge, the others stay where they are.
+    pub fn with_height(&self, height: f32) -> LayoutArea {
+        let bottom = self.rect.y + self.rect.height;
+        let shift = height - self.rect.height;
+        LayoutArea {
+            rect: Rect {
+                height,
+                ..self.rect
+            },
+            insets: self.insets,
+            reserved: self
+                .reserved
+                .iter()
+                .map(|reserved| {
+                    if (reserved.y + reserved.height - bottom).abs() <= EPSILON {
+                        Rect {
+                            y: reserved.y + shift,
+                            ..*reserved
+                        }
+                    } else {
+                        *reserved
+                    }
+                })
+                .collect(),
+        }
+    }
+
+    /// Whether `rect` is outside the usable rect or overlaps reserved space,
+    /// by more than rounding errors.
+    pub fn is_blocked(&self, rect: Rect) -> bool {
+        let usable = self.usable();
+        let outside = rect.x < usable.x - EPSILON
+            || rect.y < usable.y - EPSILON
+            || rect.x + rect.width > usable.x + usable.width + EPSILON
+            || rect.y + rect.height > usable.y + usable.height + EPSILON;
+        outside
+            || self.reserved.iter().any(|reserved| {
+                (rect.x + rect.width).min(reserved.x + reserved.width) - rect.x.max(reserved.x)
+                    > EPSILON
+                    && (rect.y + rect.height).min(reserved.y + reserved.height)
+                        - rect.y.max(reserved.y)
+                        > EPSILON
+            })
+    }
+}
@@ -0,0 +1,189 @@
+/// How windows are matched to the slots computed by a layout.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Assignment {
+    /// Minimizes the total squared distance travelled by the windows
+    /// (Hungarian algorithm, `O(n²·m)`).
+    #[default]
+    Optimal,
+    /// Assigns each window, in order, to the closest slot still free. Cheaper,
+    /// but depends on the order of the windows.
+    Greedy,
+}
+
+impl Assignment {
+    /// Returns, for each source point, the index of the target it is assigned
+    /// to. There must be at least as many targets as sources.
+    pub fn assign(self, sources: &[(f32, f32)], targets: &[(f32, f32)]) -> Vec<usize> {
+        assert!(sources.len() <= targets.len());
+        match self {
+            Assignment::Optimal => optimal(sources, targets),
+            Assignment::Greedy => greedy(sources, targets),
+        }
+    }
+}
+
+fn squared_distance(a: (f32, f32), b: (f32, f32)) -> f64 {
+    let dx = (a.0 - b.0) as f64;
+    let dy = (a.1 - b.1) as f64;
+    dx * dx + dy * dy
+}
+
+fn greedy(sources: &[(f32, f32)], targets: &[(f32, f32)]) -> Vec<usize> {
+    let mut assigned = vec![false; targets.len()];
+    sources
+        .iter()
+        .map(|source| {
+            let (closest, _) = targets
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| !assigned[*index])
+                .map(|(index, target)| (index, squared_distance(*source, *target)))
+                .min_by(|a, b| a.1.total_cmp(&b.1))
+                .unwrap();
+            assigned[closest] = true;
+            closest
+        })
+        .collect()
+}
+
+/// Hungarian algorithm with potentials on a `sources x targets` cost matrix,
+/// indices are 1-based with 0 used as a sentinel.
+fn optimal(sources: &[(f32, f32)], targets: &[(f32, f32)]) -> Vec<usize> {
+    let n = sources.len();
+    let m = targets.len();
+    let cost = |i: usize, j: usize| squared_distance(sources[i - 1], targets[j - 1]);
+
+    let mut u = vec![0.0; n + 1];
+    let mut v = vec![0.0; m + 1];
+    // p[j]: source assigned to target j, way[j]: previous target in the path
+    let mut p = vec![0; m + 1];
+    let mut way = vec![0; m + 1];
+
+    for i in 1..=n {
+        p[0] = i;
+        let mut j0 = 0;
+        let mut min_v = vec![f64::INFINITY; m + 1];
+        let mut used = vec![false; m + 1];
+        loop {
+            used[j0] = true;
+            let i0 = p[j0];
+            let mut delta = f64::INFINITY;
+            let mut j1 = 0;
+            for j in 1..=m {
+                if used[j] {
+                    continue;
+                }
+                let current = cost(i0, j) - u[i0] - v[j];
+                if current < min_v[j] {
+                    min_v[j] = current;
+                    way[j] = j0;
+                }
+                if min_v[j] < delta {
+                    delta = min_v[j];
+                    j1 = j;
+                }
+            }
+            for j in 0..=m {
+                if used[j] {
+                    u[p[j]] += delta;
+                    v[j] -= delta;
+                } else {
+                    min_v[j] -= delta;
+                }
+            }
+            j0 = j1;
+            if p[j0] == 0 {
+                break;
+            }
+        }
+        loop {
+            let j1 = way[j0];
+            p[j0] = p[j1];
+            j0 = j1;
+            if j0 == 0 {
+                break;
+            }
+        }
+    }
+
+    let mut assignment = vec![0; n];
+    for j in 1..=m {
+        if p[j] != 0 {
+            assignment[p[j] - 1] = j - 1;
+        }
+    }
+    assignment
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Points scattered by a fixed linear congruential sequence.
+    fn points(count: usize, seed: u32) -> Vec<(f32, f32)> {
+        let mut state = seed;
+        let mut next = move || {
+            state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            (state >> 8) as f32 / (1 << 24) as f32 * 2000.0
+        };
+        (0..count).map(|_| (next(), next())).collect()
+    }
+
+    fn total_cost(sources: &[(f32, f32)], targets: &[(f32, f32)], assignment: &[usize]) -> f64 {
+        sources
+            .iter()
+            .zip(assignment)
+            .map(|(source, &target)| squared_distance(*source, targets[target]))
+            .sum()
+    }
+
+    /// Lowest total cost over every assignment of the remaining sources to
+    /// the free targets.
+    fn brute_force(sources: &[(f32, f32)], targets: &[(f32, f32)], used: &mut [bool]) -> f64 {
+        let Some((source, rest)) = sources.split_first() else {
+            return 0.0;
+        };
+        let mut best = f64::INFINITY;
+        for j in 0..targets.len() {
+            if used[j] {
+                continue;
+            }
+            used[j] = true;
+            let cost = squared_distance(*source, targets[j]) + brute_force(rest, targets, used);
+            used[j] = false;
+            best = best.min(cost);
+        }
+        best
+    }
+
+    #[test]
+    fn optimal_matches_brute_force() {
+        for seed in 0..20 {
+            let sources = points(6, seed);
+            let targets = points(7, seed + 100);
+            let assignment = Assignment::Optimal.assign(&sources, &targets);
+
+            let mut seen = assignment.clone();
+            seen.sort_unstable();
+            seen.dedup();
+            assert_eq!(seen.len(), sources.len(), "{assignment:?}");
+            let best = brute_force(&sources, &targets, &mut vec![false; targets.len()]);
+            let cost = total_cost(&sources, &targets, &assignment);
+            assert!((cost - best).abs() < 1e-6 * best, "{cost} {best}");
+        }
+    }
+
+    #[test]
+    fn optimal_does_not_depend_on_the_order() {
+        for seed in 0..20 {
+            let sources = points(8, seed);
+            let targets = points(8, seed + 100);
+            let assignment = Assignment::Optimal.assign(&sources, &targets);
+
+            let reversed: Vec<_> = sources.iter().rev().copied().collect();
+            let mut again = Assignment::Optimal.assign(&reversed, &targets);
+            again.reverse();
+            assert_eq!(assignment, again);
+        }
+    }
+}
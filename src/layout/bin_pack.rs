@@ -0,0 +1,423 @@
+use binpack2d::{bin_new, BinType, Dimension};
+
+use super::{align, Expose, LayoutArea, LayoutOptions, LayoutStrategy, Placement, Rect};
+use crate::packer::Bin;
+use crate::window::WindowGeometry;
+
+/// Units per pixel of the binpack2d packers, which only handle integer sizes.
+const SUBPIXELS: f32 = 16.0;
+
+/// Distributes the windows, largest first, into bins the size of the usable
+/// area, with its reserved rectangles already occupied. Each bin is a page of
+/// the overview.
+///
+/// Windows are all shrunk by the same scale, enough for the largest one and
+/// its caption to fit in a bin.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BinPack;
+
+impl LayoutStrategy for BinPack {
+    fn layout(
+        &self,
+        windows: &[WindowGeometry],
+        area: &LayoutArea,
+        options: &LayoutOptions,
+    ) -> Vec<Placement> {
+        // Sort windows in decreasing order of size
+        let mut windows = windows.to_vec();
+        windows.sort_by(|a, b| b.area().partial_cmp(&a.area()).unwrap());
+
+        let area = options.inset(area);
+        let output = area.usable();
+        let spacing = options.spacing;
+        let empty = area.bin(spacing);
+        let scale = windows
+            .iter()
+            .map(|window| {
+                empty
+                    .free()
+                    .iter()
+                    .map(|free| {
+                        options.scale_to_fit(window, free.width - spacing, free.height - spacing)
+                    })
+                    .fold(0.0, f32::max)
+            })
+            .fold(options.max_scale, f32::min);
+
+        let mut bins: Vec<Bin> = Vec::new();
+
+        for window in windows.iter() {
+            let (width, height) = options.slot_size(window, scale);
+            let (width, height) = (width + spacing, height + spacing);
+            let mut best_fit = None;
+            let mut min_empty_space = f32::MAX;
+
+            for (i, bin) in bins.iter().enumerate() {
+                if let Some(empty_space) = bin.empty_space_after_insertion(width, height) {
+                    if empty_space < min_empty_space {
+                        best_fit = Some(i);
+                        min_empty_space = empty_space;
+                    }
+                }
+            }
+
+            if let Some(i) = best_fit {
+                bins[i].add(window.id, width, height);
+            } else {
+                let mut bin = empty.clone();
+                bin.add(window.id, width, height);
+                bins.push(bin);
+            }
+        }
+
+        bins.iter()
+            .enumerate()
+            .flat_map(|(page, bin)| {
+                let offset_x = output.x + area.rect.width * page as f32;
+                bin.placed().iter().map(move |(id, rect)| Placement {
+                    page,
+                    ..Placement::new(*id, offset_x + rect.x, output.y + rect.y, scale)
+                })
+            })
+            .collect()
+    }
+}
+
+/// Algorithm used by [`BinPack2`] to place the windows.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PackerKind {
+    /// binpack2d MaxRects. The binpack2d packers have no notion of reserved
+    /// space, [`PackerKind::Bin`] is used instead when the area has some.
+    #[default]
+    MaxRects,
+    /// binpack2d Guillotine.
+    Guillotine,
+    /// The in-crate [`Bin`], working with sub-pixel sizes and packing around
+    /// the reserved rectangles.
+    Bin,
+}
+
+impl PackerKind {
+    pub const ALL: [PackerKind; 3] = [
+        PackerKind::MaxRects,
+        PackerKind::Guillotine,
+        PackerKind::Bin,
+    ];
+
+    /// Packer actually used in `area`, [`PackerKind::Bin`] when it has
+    /// reserved space the binpack2d packers can't pack around.
+    pub fn for_area(self, area: &LayoutArea) -> PackerKind {
+        if area.reserved.is_empty() {
+            self
+        } else {
+            PackerKind::Bin
+        }
+    }
+}
+
+/// Packs the windows into the usable area with a 2D bin packer, at the
+/// largest scale where every window and its caption fit.
+#[derive(Clone, Copy, Debug)]
+pub struct BinPack2 {
+    pub packer: PackerKind,
+    /// Runs every [`PackerKind`] usable in the area and keeps the one packing
+    /// at the largest scale, `packer` is ignored.
+    pub compare: bool,
+    /// The search stops once the scale is known within this tolerance.
+    pub precision: f32,
+    /// Upper bound on the number of packings tried.
+    pub max_attempts: usize,
+    /// Centers the packed windows in the area, instead of leaving them in the
+    /// top left corner. Skipped if it would move them over reserved space.
+    pub center: bool,
+    /// Spreads the rows of packed windows evenly over the area height and
+    /// centers each of them, takes precedence over `center`. Skipped if it
+    /// would move them over reserved space.
+    pub balance: bool,
+}
+
+impl Default for BinPack2 {
+    fn default() -> Self {
+        BinPack2 {
+            packer: PackerKind::default(),
+            compare: false,
+            precision: 0.001,
+            max_attempts: 32,
+            center: true,
+            balance: false,
+        }
+    }
+}
+
+/// Outcome of [`BinPack2::pack`].
+#[derive(Clone, Debug)]
+pub struct PackResult {
+    pub placements: Vec<Placement>,
+    /// Scale the windows were packed at.
+    pub scale: f32,
+    /// Number of packings tried to find the scale.
+    pub attempts: usize,
+    /// Packer that produced the placements.
+    pub packer: PackerKind,
+}
+
+impl BinPack2 {
+    /// Rectangle `packer` packs into.
+    fn region(packer: PackerKind, area: &LayoutArea) -> Rect {
+        match packer {
+            PackerKind::Bin => area.usable(),
+            PackerKind::MaxRects | PackerKind::Guillotine => area.largest_free_rect(),
+        }
+    }
+
+    /// Packs the windows at the given scale, returning `None` if any window
+    /// is left out.
+    fn try_pack(
+        &self,
+        packer: PackerKind,
+        windows: &[WindowGeometry],
+        area: &LayoutArea,
+        options: &LayoutOptions,
+        scale: f32,
+    ) -> Option<Vec<Placement>> {
+        let bin_type = match packer {
+            PackerKind::MaxRects => BinType::MaxRects,
+            PackerKind::Guillotine => BinType::Guillotine,
+            PackerKind::Bin => return self.try_pack_bin(windows, area, options, scale),
+        };
+        let output = Self::region(packer, area);
+        // half the spacing is padded on each side, the bin is grown by the
+        // spacing so the outer padding doesn't count
+        let padding = options.spacing * 0.5;
+        // sizes are rounded up so the exactly scaled windows always fit in
+        // their rects, and positions are converted back without rounding
+        let dimensions: Vec<Dimension> = windows
+            .iter()
+            .map(|window| {
+                let (width, height) = options.slot_size(window, scale);
+                Dimension::with_id(
+                    window.id as isize,
+                    (width * SUBPIXELS).ceil() as i32,
+                    (height * SUBPIXELS).ceil() as i32,
+                    (padding * SUBPIXELS) as i32,
+                )
+            })
+            .collect();
+
+        let mut bin = bin_new(
+            bin_type,
+            ((output.width + options.spacing) * SUBPIXELS).floor() as i32,
+            ((output.height + options.spacing) * SUBPIXELS).floor() as i32,
+        );
+        let (inserted, rejected) = bin.insert_list(&dimensions);
+        if !rejected.is_empty() || inserted.len() != windows.len() {
+            return None;
+        }
+
+        windows
+            .iter()
+            .zip(&dimensions)
+            .map(|(window, dimension)| {
+                let rect = bin.find_by_id(window.id as isize)?;
+                // Guillotine may turn a rect sideways, the window doesn't fit
+                // it then
+                if rect.width() != dimension.width() || rect.height() != dimension.height() {
+                    return None;
+                }
+                Some(Placement::new(
+                    window.id,
+                    output.x - padding + rect.x() as f32 / SUBPIXELS,
+                    output.y - padding + rect.y() as f32 / SUBPIXELS,
+                    scale,
+                ))
+            })
+            .collect()
+    }
+
+    fn try_pack_bin(
+        &self,
+        windows: &[WindowGeometry],
+        area: &LayoutArea,
+        options: &LayoutOptions,
+        scale: f32,
+    ) -> Option<Vec<Placement>> {
+        let mut windows = windows.to_vec();
+        windows.sort_by(|a, b| b.area().total_cmp(&a.area()));
+
+        let output = area.usable();
+        let mut bin = area.bin(options.spacing);
+        windows
+            .iter()
+            .map(|window| {
+                let (width, height) = options.slot_size(window, scale);
+                let rect = bin.add(window.id, width + options.spacing, height + options.spacing)?;
+                Some(Placement::new(
+                    window.id,
+                    output.x + rect.x,
+                    output.y + rect.y,
+                    scale,
+                ))
+            })
+            .collect()
+    }
+
+    /// Packs the windows with the configured packer, or with all of them in
+    /// comparison mode.
+    pub fn pack(
+        &self,
+        windows: &[WindowGeometry],
+        area: &LayoutArea,
+        options: &LayoutOptions,
+    ) -> PackResult {
+        let area = options.inset(area);
+        let mut result = self.pack_unaligned(windows, &area, options);
+        let region = Self::region(result.packer, &area);
+        let mut aligned = result.placements.clone();
+        if self.balance {
+            align::balance(&mut aligned, windows, region, options);
+        } else if self.center {
+            align::center(&mut aligned, windows, region, options);
+        }
+        let blocked = aligned.iter().any(|placement| {
+            windows
+                .iter()
+                .find(|window| window.id == placement.id)
+                .is_some_and(|window| {
+                    let (width, height) = options.slot_size(window, placement.scale);
+                    area.is_blocked(Rect::new(placement.x, placement.y, width, height))
+                })
+        });
+        if !blocked {
+            result.placements = aligned;
+        }
+        result
+    }
+
+    fn pack_unaligned(
+        &self,
+        windows: &[WindowGeometry],
+        area: &LayoutArea,
+        options: &LayoutOptions,
+    ) -> PackResult {
+        if !self.compare {
+            return self.pack_with(self.packer.for_area(area), windows, area, options);
+        }
+
+        let mut attempts = 0;
+        let mut best: Option<PackResult> = None;
+        for packer in PackerKind::ALL
+            .into_iter()
+            .filter(|packer| packer.for_area(area) == *packer)
+        {
+            let result = self.pack_with(packer, windows, area, options);
+            attempts += result.attempts;
+            let better = match &best {
+                Some(best) => result.scale > best.scale,
+                None => true,
+            };
+            if better {
+                best = Some(result);
+            }
+        }
+        PackResult {
+            attempts,
+            ..best.unwrap()
+        }
+    }
+
+    /// Bisects the scale between a lower bound and the largest scale allowed
+    /// by the free space. If no packing succeeds, every window is placed in
+    /// an [`Expose`] grid instead.
+    fn pack_with(
+        &self,
+        packer: PackerKind,
+        windows: &[WindowGeometry],
+        area: &LayoutArea,
+        options: &LayoutOptions,
+    ) -> PackResult {
+        if windows.is_empty() {
+            return PackResult {
+                placements: Vec::new(),
+                scale: 1.0,
+                attempts: 0,
+                packer,
+            };
+        }
+
+        let free = area.free_rects();
+        let total_window_area: f32 = windows.iter().map(WindowGeometry::area).sum();
+        let total_bin_area: f32 = free.iter().map(|rect| rect.width * rect.height).sum();
+        // each window has to fit in at least one of the free rectangles
+        let mut high = windows
+            .iter()
+            .map(|window| {
+                free.iter()
+                    .map(|rect| options.scale_to_fit(window, rect.width, rect.height))
+                    .fold(0.0, f32::max)
+            })
+            .fold((total_bin_area / total_window_area).sqrt(), f32::min)
+            .min(options.max_scale);
+        let mut low = 0.0;
+
+        let mut attempts = 1;
+        if let Some(placements) = self.try_pack(packer, windows, area, options, high) {
+            return PackResult {
+                placements,
+                scale: high,
+                attempts,
+                packer,
+            };
+        }
+
+        let mut best = None;
+        while high - low > self.precision && attempts < self.max_attempts {
+            let scale = (low + high) * 0.5;
+            attempts += 1;
+            match self.try_pack(packer, windows, area, options, scale) {
+                Some(placements) => {
+                    low = scale;
+                    best = Some(placements);
+                }
+                None => high = scale,
+            }
+        }
+
+        match best {
+            Some(placements) => PackResult {
+                placements,
+                scale: low,
+                attempts,
+                packer,
+            },
+            None => {
+                // the margin is already part of the area
+                let options = LayoutOptions {
+                    margin: 0.0,
+                    ..*options
+                };
+                let placements = Expose::default().layout(windows, area, &options);
+                let scale = placements
+                    .iter()
+                    .map(|placement| placement.scale)
+                    .fold(f32::MAX, f32::min);
+                PackResult {
+                    placements,
+                    scale,
+                    attempts,
+                    packer,
+                }
+            }
+        }
+    }
+}
+
+impl LayoutStrategy for BinPack2 {
+    fn layout(
+        &self,
+        windows: &[WindowGeometry],
+        area: &LayoutArea,
+        options: &LayoutOptions,
+    ) -> Vec<Placement> {
+        self.pack(windows, area, options).placements
+    }
+}
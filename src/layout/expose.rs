@@ -0,0 +1,184 @@
+use super::{Assignment, LayoutArea, LayoutOptions, LayoutStrategy, Placement, Rect};
+use crate::window::WindowGeometry;
+
+/// Arranges the windows in a grid, each window and its caption scaled to fit
+/// its cell, up to the maximum scale, and centered in it. Cells are `spacing`
+/// apart.
+///
+/// The number of rows and columns is the one giving the largest thumbnails on
+/// average for the usable area. Windows in a cell crossed by a reserved
+/// rectangle shrink to the largest part of the cell left free.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Expose {
+    pub assignment: Assignment,
+}
+
+struct Grid {
+    output: Rect,
+    options: LayoutOptions,
+    num_rows: usize,
+    num_cols: usize,
+    cell_width: f32,
+    cell_height: f32,
+}
+
+impl Grid {
+    fn with_size(output: Rect, options: LayoutOptions, num_rows: usize, num_cols: usize) -> Self {
+        let spacing = options.spacing;
+        Grid {
+            output,
+            options,
+            num_rows,
+            num_cols,
+            cell_width: ((output.width - spacing * (num_cols - 1) as f32) / num_cols as f32)
+                .max(0.0),
+            cell_height: ((output.height - spacing * (num_rows - 1) as f32) / num_rows as f32)
+                .max(0.0),
+        }
+    }
+
+    /// Picks the grid maximizing the average scale of the windows, taking into
+    /// account both the output and the windows aspect ratio.
+    fn new(windows: &[WindowGeometry], output: Rect, options: LayoutOptions) -> Self {
+        let num_windows = windows.len().max(1);
+        let mut best = Grid::with_size(output, options, 1, num_windows);
+        let mut best_scale = best.average_scale(windows);
+
+        for num_rows in 2..=num_windows {
+            let num_cols = num_windows.div_ceil(num_rows);
+            // skip grids with a whole row left empty
+            if (num_rows - 1) * num_cols >= num_windows {
+                continue;
+            }
+            let grid = Grid::with_size(output, options, num_rows, num_cols);
+            let scale = grid.average_scale(windows);
+            if scale > best_scale {
+                best = grid;
+                best_scale = scale;
+            }
+        }
+        best
+    }
+
+    fn average_scale(&self, windows: &[WindowGeometry]) -> f32 {
+        let scales: Vec<f32> = windows
+            .iter()
+            .filter(|window| window.area() > 0.0)
+            .map(|window| self.scale_to_fit(window))
+            .collect();
+        if scales.is_empty() {
+            return 0.0;
+        }
+        scales.iter().sum::<f32>() / scales.len() as f32
+    }
+
+    fn cell_center(&self, row: usize, col: usize) -> (f32, f32) {
+        let cell = self.cell_rect(row, col);
+        (cell.x + cell.width * 0.5, cell.y + cell.height * 0.5)
+    }
+
+    fn cell_rect(&self, row: usize, col: usize) -> Rect {
+        let spacing = self.options.spacing;
+        Rect::new(
+            self.output.x + col as f32 * (self.cell_width + spacing),
+            self.output.y + row as f32 * (self.cell_height + spacing),
+            self.cell_width,
+            self.cell_height,
+        )
+    }
+
+    fn scale_to_fit(&self, window: &WindowGeometry) -> f32 {
+        self.options
+            .scale_to_fit(window, self.cell_width, self.cell_height)
+    }
+
+    /// Assigns each window to a cell, returning its row and column.
+    fn assign(&self, windows: &[WindowGeometry], assignment: Assignment) -> Vec<(usize, usize)> {
+        let centers: Vec<(f32, f32)> = windows
+            .iter()
+            .map(|window| {
+                (
+                    window.x + window.width * window.scale * 0.5,
+                    window.y + window.height * window.scale * 0.5,
+                )
+            })
+            .collect();
+        let cells: Vec<(f32, f32)> = (0..self.num_rows)
+            .flat_map(|row| (0..self.num_cols).map(move |col| (row, col)))
+            .map(|(row, col)| self.cell_center(row, col))
+            .collect();
+
+        assignment
+            .assign(&centers, &cells)
+            .into_iter()
+            .map(|cell| (cell / self.num_cols, cell % self.num_cols))
+            .collect()
+    }
+}
+
+impl LayoutStrategy for Expose {
+    fn layout(
+        &self,
+        windows: &[WindowGeometry],
+        area: &LayoutArea,
+        options: &LayoutOptions,
+    ) -> Vec<Placement> {
+        let grid = Grid::new(windows, options.inset(area).usable(), *options);
+
+        windows
+            .iter()
+            .zip(grid.assign(windows, self.assignment))
+            .map(|(window, (row, col))| {
+                let cell = grid.cell_rect(row, col);
+                let (cell, scale) = area
+                    .free_rects_in(cell)
+                    .into_iter()
+                    .map(|free| {
+                        let scale = options.scale_to_fit(window, free.width, free.height);
+                        (free, scale)
+                    })
+                    .max_by(|(_, a), (_, b)| a.total_cmp(b))
+                    .unwrap_or((cell, 0.0));
+                let (width, height) = options.slot_size(window, scale);
+                let x = cell.x + (cell.width - width) * 0.5;
+                let y = cell.y + (cell.height - height) * 0.5;
+                Placement::new(window.id, x, y, scale)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ten_windows_on_a_wide_output_use_a_wide_grid() {
+        let windows: Vec<WindowGeometry> = (0..10)
+            .map(|id| WindowGeometry {
+                id,
+                x: id as f32 * 150.0,
+                y: id as f32 * 90.0,
+                width: 1920.0,
+                height: 1080.0,
+                scale: 1.0,
+            })
+            .collect();
+        let output = Rect::new(0.0, 0.0, 3440.0, 1440.0);
+        let options = LayoutOptions::default();
+
+        let grid = Grid::new(&windows, output, options);
+        assert!(grid.num_rows * grid.num_cols >= windows.len());
+        assert_ne!((grid.num_rows, grid.num_cols), (4, 4));
+
+        // the grid expose used to pick, ceil(sqrt(n)) on each side
+        let side = (windows.len() as f32).sqrt().ceil() as usize;
+        let square = Grid::with_size(output, options, side, side);
+        assert!(
+            grid.average_scale(&windows) > square.average_scale(&windows),
+            "{}x{}",
+            grid.num_rows,
+            grid.num_cols
+        );
+    }
+}
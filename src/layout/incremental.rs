@@ -0,0 +1,130 @@
+use super::{page_count, LayoutArea, LayoutOptions, LayoutStrategy, Placement, Rect};
+use crate::window::WindowGeometry;
+
+/// Smallest scale tried for an inserted window, relative to the scale of the
+/// thumbnails already shown, before giving up on the free space.
+const MIN_RELATIVE_SCALE: f32 = 0.5;
+/// Factor the scale of an inserted window is reduced by at each attempt.
+const SCALE_STEP: f32 = 0.9;
+
+/// Updates a layout shown when a window appears or disappears, keeping the
+/// other thumbnails in place whenever possible.
+pub struct Incremental<'a> {
+    /// Strategy re-run when a new window doesn't fit in the free space.
+    pub strategy: &'a dyn LayoutStrategy,
+}
+
+/// Median scale of `placements`, the size new thumbnails are matched to.
+fn typical_scale(placements: &[Placement]) -> Option<f32> {
+    let mut scales: Vec<f32> = placements.iter().map(|placement| placement.scale).collect();
+    scales.sort_by(f32::total_cmp);
+    scales.get(scales.len() / 2).copied()
+}
+
+impl Incremental<'_> {
+    /// Adds `window` to the layout `placements` of the windows already shown.
+    ///
+    /// The window is packed in the space left free by the other thumbnails,
+    /// on the first page it fits in, at the typical scale of the thumbnails
+    /// or somewhat smaller. If there is no room, the strategy lays out every
+    /// window again, starting from the thumbnails where they are so the
+    /// strategies matching windows to slots by position move them little.
+    pub fn insert(
+        &self,
+        windows: &[WindowGeometry],
+        placements: &[Placement],
+        window: &WindowGeometry,
+        area: &LayoutArea,
+        options: &LayoutOptions,
+    ) -> Vec<Placement> {
+        if let Some(placement) = self.fit(windows, placements, window, area, options) {
+            let mut placements = placements.to_vec();
+            placements.push(placement);
+            return placements;
+        }
+
+        let mut shown: Vec<WindowGeometry> = windows
+            .iter()
+            .filter(|shown| shown.id != window.id)
+            .map(|window| {
+                match placements
+                    .iter()
+                    .find(|placement| placement.id == window.id)
+                {
+                    Some(placement) => WindowGeometry {
+                        x: placement.x - area.rect.width * placement.page as f32,
+                        y: placement.y,
+                        scale: placement.scale,
+                        ..*window
+                    },
+                    None => *window,
+                }
+            })
+            .collect();
+        shown.push(*window);
+        self.strategy.layout(&shown, area, options)
+    }
+
+    /// Placement for `window` in the free space around `placements`.
+    fn fit(
+        &self,
+        windows: &[WindowGeometry],
+        placements: &[Placement],
+        window: &WindowGeometry,
+        area: &LayoutArea,
+        options: &LayoutOptions,
+    ) -> Option<Placement> {
+        let typical = typical_scale(placements)?;
+        let inset = options.inset(area);
+        let usable = inset.usable();
+        let spacing = options.spacing;
+
+        for page in 0..page_count(placements) {
+            let offset_x = area.rect.width * page as f32;
+            let mut bin = inset.bin(spacing);
+            for placement in placements.iter().filter(|placement| placement.page == page) {
+                let Some(shown) = windows.iter().find(|window| window.id == placement.id) else {
+                    continue;
+                };
+                let (width, height) = options.slot_size(shown, placement.scale);
+                // the new slot is padded on its right and bottom, so the
+                // thumbnails only need the spacing on theirs
+                bin.reserve(Rect::new(
+                    placement.x - offset_x - usable.x,
+                    placement.y - usable.y,
+                    width + spacing,
+                    height + spacing,
+                ));
+            }
+
+            let mut scale = typical.min(options.max_scale);
+            while scale >= typical * MIN_RELATIVE_SCALE {
+                let (width, height) = options.slot_size(window, scale);
+                if let Some(rect) = bin.add(window.id, width + spacing, height + spacing) {
+                    return Some(Placement {
+                        page,
+                        ..Placement::new(
+                            window.id,
+                            usable.x + offset_x + rect.x,
+                            usable.y + rect.y,
+                            scale,
+                        )
+                    });
+                }
+                scale *= SCALE_STEP;
+            }
+        }
+        None
+    }
+
+    /// Removes the window `id` from the layout `placements`. The other
+    /// thumbnails stay where they are, the space left is used by the next
+    /// insertion.
+    pub fn remove(&self, placements: &[Placement], id: usize) -> Vec<Placement> {
+        placements
+            .iter()
+            .filter(|placement| placement.id != id)
+            .copied()
+            .collect()
+    }
+}
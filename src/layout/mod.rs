@@ -0,0 +1,95 @@
+mod align;
+mod area;
+mod assignment;
+mod bin_pack;
+mod expose;
+mod incremental;
+mod natural;
+mod normalize;
+mod options;
+mod outputs;
+mod rows;
+mod scrollable;
+
+pub use area::{Insets, LayoutArea};
+pub use assignment::Assignment;
+pub use bin_pack::{BinPack, BinPack2, PackResult, PackerKind};
+pub use expose::Expose;
+pub use incremental::Incremental;
+pub use natural::Natural;
+pub use normalize::Normalize;
+pub use options::LayoutOptions;
+pub use outputs::{MultiOutput, Output, OutputAssignment};
+pub use rows::JustifiedRows;
+pub use scrollable::{Scrollable, ScrollableLayout};
+
+use crate::window::WindowGeometry;
+
+/// Rectangle of the output the windows are laid out into.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Rect {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl Rect {
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Rect {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+}
+
+/// Target position and scale computed by a strategy for the window `id`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Placement {
+    pub id: usize,
+    pub x: f32,
+    pub y: f32,
+    pub scale: f32,
+    /// Page of the overview the window is on. Pages are laid out side by
+    /// side, page `n` being offset by `n` widths of the area laid out, of
+    /// all the outputs for [`MultiOutput`].
+    pub page: usize,
+}
+
+impl Placement {
+    pub fn new(id: usize, x: f32, y: f32, scale: f32) -> Self {
+        Placement {
+            id,
+            x,
+            y,
+            scale,
+            page: 0,
+        }
+    }
+}
+
+/// Number of pages spanned by `placements`.
+pub fn page_count(placements: &[Placement]) -> usize {
+    placements
+        .iter()
+        .map(|placement| placement.page + 1)
+        .max()
+        .unwrap_or(1)
+}
+
+/// A way of arranging windows inside an output.
+///
+/// Strategies only compute where windows should go; applying the result (and
+/// choosing the transition) is left to the caller. Windows are kept inside the
+/// usable part of the area and off its reserved rectangles, and follow the
+/// spacing, caption and scale limits of the options.
+pub trait LayoutStrategy {
+    fn layout(
+        &self,
+        windows: &[WindowGeometry],
+        area: &LayoutArea,
+        options: &LayoutOptions,
+    ) -> Vec<Placement>;
+}
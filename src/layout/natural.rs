@@ -0,0 +1,176 @@
+use super::{Expose, LayoutArea, LayoutOptions, LayoutStrategy, Placement, Rect};
+use crate::window::WindowGeometry;
+
+/// Overlap, in pixels, below which two windows are considered apart.
+const EPSILON: f32 = 0.001;
+/// Angle between the directions windows sharing a center are pushed in, so
+/// they fan out around it.
+const GOLDEN_ANGLE: f32 = 2.399_963;
+
+/// Halvings of the range the scale of the arrangement is searched in.
+const BISECTION_STEPS: usize = 20;
+
+/// Keeps the windows where the user left them, scaling them down and pushing
+/// them apart until they don't overlap, at the largest scale the whole
+/// arrangement fits the largest free rectangle of the area at. Windows are
+/// taken at their current scale, so running the layout again from its own
+/// result keeps it.
+///
+/// The windows are pushed apart once scaled, so the spacing and captions
+/// keep their configured size whatever the scale. When the windows still
+/// overlap after `max_iterations` at every scale, they are laid out by
+/// [`Expose`] instead.
+#[derive(Clone, Copy, Debug)]
+pub struct Natural {
+    /// Upper bound on the separation passes over all the window pairs.
+    pub max_iterations: usize,
+}
+
+impl Default for Natural {
+    fn default() -> Self {
+        Natural {
+            max_iterations: 100,
+        }
+    }
+}
+
+/// Separated rects of the windows, their captions included, and their bounds.
+struct Arrangement {
+    rects: Vec<Rect>,
+    bounds: Rect,
+}
+
+impl Natural {
+    /// Moves overlapping windows apart along the line between their centers,
+    /// half each, until no pair is closer than `spacing`. Returns whether the
+    /// windows are apart.
+    fn separate(&self, rects: &mut [Rect], spacing: f32) -> bool {
+        for _ in 0..self.max_iterations {
+            let mut overlapping = false;
+            for i in 0..rects.len() {
+                for j in (i + 1)..rects.len() {
+                    let (a, b) = (rects[i], rects[j]);
+                    let overlap_x = (a.x + a.width).min(b.x + b.width) - a.x.max(b.x) + spacing;
+                    let overlap_y = (a.y + a.height).min(b.y + b.height) - a.y.max(b.y) + spacing;
+                    if overlap_x <= EPSILON || overlap_y <= EPSILON {
+                        continue;
+                    }
+                    overlapping = true;
+
+                    let delta_x = (b.x + b.width * 0.5) - (a.x + a.width * 0.5);
+                    let delta_y = (b.y + b.height * 0.5) - (a.y + a.height * 0.5);
+                    let length = delta_x.hypot(delta_y);
+                    let (direction_x, direction_y) = if length > EPSILON {
+                        (delta_x / length, delta_y / length)
+                    } else {
+                        let angle = j as f32 * GOLDEN_ANGLE;
+                        (angle.cos(), angle.sin())
+                    };
+                    // distance along the direction clearing either overlap
+                    let distance =
+                        (overlap_x / direction_x.abs()).min(overlap_y / direction_y.abs());
+                    let push = distance * 0.5;
+                    rects[i].x -= direction_x * push;
+                    rects[i].y -= direction_y * push;
+                    rects[j].x += direction_x * push;
+                    rects[j].y += direction_y * push;
+                }
+            }
+            if !overlapping {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Windows scaled by `scale` around the origin, with their captions, and
+    /// pushed apart.
+    fn arrange(
+        &self,
+        windows: &[WindowGeometry],
+        scale: f32,
+        options: &LayoutOptions,
+    ) -> Option<Arrangement> {
+        let mut rects: Vec<Rect> = windows
+            .iter()
+            .map(|window| {
+                let (width, height) = options.slot_size(window, window.scale * scale);
+                Rect::new(window.x * scale, window.y * scale, width, height)
+            })
+            .collect();
+        if !self.separate(&mut rects, options.spacing) {
+            return None;
+        }
+
+        let min_x = rects.iter().map(|r| r.x).fold(f32::MAX, f32::min);
+        let min_y = rects.iter().map(|r| r.y).fold(f32::MAX, f32::min);
+        let max_x = rects.iter().map(|r| r.x + r.width).fold(f32::MIN, f32::max);
+        let max_y = rects
+            .iter()
+            .map(|r| r.y + r.height)
+            .fold(f32::MIN, f32::max);
+        Some(Arrangement {
+            rects,
+            bounds: Rect::new(min_x, min_y, max_x - min_x, max_y - min_y),
+        })
+    }
+}
+
+impl LayoutStrategy for Natural {
+    fn layout(
+        &self,
+        windows: &[WindowGeometry],
+        area: &LayoutArea,
+        options: &LayoutOptions,
+    ) -> Vec<Placement> {
+        if windows.is_empty() {
+            return Vec::new();
+        }
+        let output = options.inset(area).largest_free_rect();
+        let fits = |arrangement: &Arrangement| {
+            arrangement.bounds.width <= output.width && arrangement.bounds.height <= output.height
+        };
+        // the thumbnails are scaled relative to the windows, no more than
+        // needed for the largest one to fill the output
+        let mut high = windows
+            .iter()
+            .map(|window| options.scale_to_fit(window, output.width, output.height) / window.scale)
+            .fold(f32::MAX, f32::min);
+        let mut low = 0.0;
+        let mut best = self
+            .arrange(windows, high, options)
+            .filter(fits)
+            .map(|arrangement| (high, arrangement));
+        if best.is_none() {
+            for _ in 0..BISECTION_STEPS {
+                let scale = (low + high) * 0.5;
+                match self.arrange(windows, scale, options).filter(fits) {
+                    Some(arrangement) => {
+                        low = scale;
+                        best = Some((scale, arrangement));
+                    }
+                    None => high = scale,
+                }
+            }
+        }
+        let Some((scale, arrangement)) = best else {
+            return Expose::default().layout(windows, area, options);
+        };
+
+        let bounds = arrangement.bounds;
+        let offset_x = output.x + (output.width - bounds.width) * 0.5 - bounds.x;
+        let offset_y = output.y + (output.height - bounds.height) * 0.5 - bounds.y;
+        windows
+            .iter()
+            .zip(arrangement.rects)
+            .map(|(window, rect)| {
+                Placement::new(
+                    window.id,
+                    offset_x + rect.x,
+                    offset_y + rect.y,
+                    scale * window.scale,
+                )
+            })
+            .collect()
+    }
+}
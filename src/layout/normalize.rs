@@ -0,0 +1,31 @@
+use super::{LayoutArea, LayoutOptions, LayoutStrategy, Placement};
+use crate::window::WindowGeometry;
+
+/// Resets the windows to their natural scale, cascading them from the top left
+/// corner of the usable area inside the margin. Reserved rectangles and the
+/// other options are ignored, the windows being back at their natural size.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Normalize;
+
+impl LayoutStrategy for Normalize {
+    fn layout(
+        &self,
+        windows: &[WindowGeometry],
+        area: &LayoutArea,
+        options: &LayoutOptions,
+    ) -> Vec<Placement> {
+        let output = options.inset(area).usable();
+        windows
+            .iter()
+            .enumerate()
+            .map(|(index, window)| {
+                Placement::new(
+                    window.id,
+                    output.x + 50.0 * index as f32,
+                    output.y + 50.0 * index as f32,
+                    1.0,
+                )
+            })
+            .collect()
+    }
+}
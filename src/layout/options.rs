@@ -0,0 +1,60 @@
+use super::{Insets, LayoutArea};
+use crate::window::WindowGeometry;
+
+/// Look of the overview, shared by every strategy.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LayoutOptions {
+    /// Space between the thumbnails and the edges of the usable area.
+    pub margin: f32,
+    /// Space between two thumbnails, captions included.
+    pub spacing: f32,
+    /// Unscaled space kept under each thumbnail for its caption.
+    pub caption_height: f32,
+    /// Largest scale a thumbnail is shown at. Above 1.0 small windows are
+    /// blown up and look blurry, so it is the default.
+    pub max_scale: f32,
+}
+
+impl Default for LayoutOptions {
+    fn default() -> Self {
+        LayoutOptions {
+            margin: 20.0,
+            spacing: 20.0,
+            caption_height: 0.0,
+            max_scale: 1.0,
+        }
+    }
+}
+
+impl LayoutOptions {
+    /// `area` with the margin added to its insets.
+    pub fn inset(&self, area: &LayoutArea) -> LayoutArea {
+        let insets = area.insets;
+        LayoutArea {
+            insets: Insets {
+                top: insets.top + self.margin,
+                right: insets.right + self.margin,
+                bottom: insets.bottom + self.margin,
+                left: insets.left + self.margin,
+            },
+            ..area.clone()
+        }
+    }
+
+    /// Size taken by `window` at `scale`, caption included.
+    pub fn slot_size(&self, window: &WindowGeometry, scale: f32) -> (f32, f32) {
+        (
+            window.width * scale,
+            window.height * scale + self.caption_height,
+        )
+    }
+
+    /// Largest scale, at most `max_scale`, at which `window` and its caption
+    /// fit in `width x height`.
+    pub fn scale_to_fit(&self, window: &WindowGeometry, width: f32, height: f32) -> f32 {
+        (width / window.width)
+            .min((height - self.caption_height) / window.height)
+            .min(self.max_scale)
+            .max(0.0)
+    }
+}
@@ -0,0 +1,173 @@
+use super::{LayoutArea, LayoutOptions, LayoutStrategy, Placement, Rect};
+use crate::window::WindowGeometry;
+
+/// A monitor, positioned in the coordinate space shared by all outputs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Output {
+    /// Logical area of the monitor, offset by its position, with its own
+    /// insets and reserved rectangles.
+    pub area: LayoutArea,
+    /// Device pixels per logical pixel.
+    pub scale_factor: f32,
+}
+
+impl Output {
+    pub fn new(rect: Rect, scale_factor: f32) -> Self {
+        Output {
+            area: LayoutArea::new(rect),
+            scale_factor,
+        }
+    }
+
+    /// Rounds a logical coordinate to the closest device pixel.
+    fn snap(&self, value: f32) -> f32 {
+        (value * self.scale_factor).round() / self.scale_factor
+    }
+}
+
+/// How windows are distributed across outputs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputAssignment {
+    /// Each window goes to the output it mostly overlaps, or the closest one
+    /// if it is on none of them.
+    #[default]
+    Overlap,
+    /// Windows are spread so each output is filled in proportion to its area,
+    /// largest windows first. Among equally filled outputs the overlapped one
+    /// is preferred.
+    Balance,
+}
+
+/// Runs a strategy on each of several outputs, with the windows assigned to
+/// it.
+pub struct MultiOutput<'a> {
+    pub strategy: &'a dyn LayoutStrategy,
+    pub assignment: OutputAssignment,
+}
+
+fn overlap(a: Rect, b: Rect) -> f32 {
+    let width = (a.x + a.width).min(b.x + b.width) - a.x.max(b.x);
+    let height = (a.y + a.height).min(b.y + b.height) - a.y.max(b.y);
+    width.max(0.0) * height.max(0.0)
+}
+
+fn squared_distance_to_center(window: &WindowGeometry, rect: Rect) -> f32 {
+    let dx = window.x + window.width * window.scale * 0.5 - (rect.x + rect.width * 0.5);
+    let dy = window.y + window.height * window.scale * 0.5 - (rect.y + rect.height * 0.5);
+    dx * dx + dy * dy
+}
+
+/// Index of the output `window` mostly overlaps, or of the closest one.
+fn overlapped_output(window: &WindowGeometry, outputs: &[Output]) -> usize {
+    let rect = Rect::new(
+        window.x,
+        window.y,
+        window.width * window.scale,
+        window.height * window.scale,
+    );
+    let (index, area) = outputs
+        .iter()
+        .enumerate()
+        .map(|(index, output)| (index, overlap(rect, output.area.rect)))
+        .fold((0, 0.0), |best, candidate| {
+            if candidate.1 > best.1 {
+                candidate
+            } else {
+                best
+            }
+        });
+    if area > 0.0 {
+        return index;
+    }
+    outputs
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| {
+            squared_distance_to_center(window, a.area.rect)
+                .total_cmp(&squared_distance_to_center(window, b.area.rect))
+        })
+        .map_or(0, |(index, _)| index)
+}
+
+impl MultiOutput<'_> {
+    /// Returns, for each window, the index of the output it is laid out on.
+    pub fn assign(&self, windows: &[WindowGeometry], outputs: &[Output]) -> Vec<usize> {
+        if outputs.is_empty() {
+            return Vec::new();
+        }
+        let overlapped: Vec<usize> = windows
+            .iter()
+            .map(|window| overlapped_output(window, outputs))
+            .collect();
+        if self.assignment == OutputAssignment::Overlap {
+            return overlapped;
+        }
+
+        let mut order: Vec<usize> = (0..windows.len()).collect();
+        order.sort_by(|a, b| windows[*b].area().total_cmp(&windows[*a].area()));
+        let mut filled = vec![0.0; outputs.len()];
+        let mut assigned = vec![0; windows.len()];
+        for index in order {
+            let area = windows[index].area();
+            let fill = |output: usize| {
+                let rect = outputs[output].area.usable();
+                (filled[output] + area) / (rect.width * rect.height)
+            };
+            let best = (0..outputs.len())
+                .min_by(|a, b| {
+                    fill(*a)
+                        .total_cmp(&fill(*b))
+                        .then_with(|| (*b == overlapped[index]).cmp(&(*a == overlapped[index])))
+                })
+                .unwrap();
+            filled[best] += area;
+            assigned[index] = best;
+        }
+        assigned
+    }
+
+    /// Lays out every output independently, with positions snapped to the
+    /// device pixels of the output each window is on. Pages are offset by the
+    /// width spanned by all the outputs rather than by that of their own
+    /// output, so every output switches page together.
+    pub fn layout_outputs(
+        &self,
+        windows: &[WindowGeometry],
+        outputs: &[Output],
+        options: &LayoutOptions,
+    ) -> Vec<Placement> {
+        let assigned = self.assign(windows, outputs);
+        let left = outputs
+            .iter()
+            .map(|output| output.area.rect.x)
+            .fold(f32::MAX, f32::min);
+        let right = outputs
+            .iter()
+            .map(|output| output.area.rect.x + output.area.rect.width)
+            .fold(f32::MIN, f32::max);
+        let page_width = right - left;
+        outputs
+            .iter()
+            .enumerate()
+            .flat_map(|(index, output)| {
+                let windows: Vec<WindowGeometry> = windows
+                    .iter()
+                    .zip(&assigned)
+                    .filter(|(_, assigned)| **assigned == index)
+                    .map(|(window, _)| *window)
+                    .collect();
+                if windows.is_empty() {
+                    return Vec::new();
+                }
+                let mut placements = self.strategy.layout(&windows, &output.area, options);
+                let page_shift = page_width - output.area.rect.width;
+                for placement in placements.iter_mut() {
+                    placement.x += placement.page as f32 * page_shift;
+                    placement.x = output.snap(placement.x);
+                    placement.y = output.snap(placement.y);
+                }
+                placements
+            })
+            .collect()
+    }
+}
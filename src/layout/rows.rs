@@ -0,0 +1,142 @@
+use std::ops::Range;
+
+use super::{LayoutArea, LayoutOptions, LayoutStrategy, Placement, Rect};
+use crate::window::WindowGeometry;
+
+/// Lays out the windows in rows of equal height that fill the output width,
+/// like a justified photo gallery. A row is never taller than its smallest
+/// window at the maximum scale.
+///
+/// Rows span the whole width, so they are laid out in the largest free
+/// rectangle of the area.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct JustifiedRows;
+
+struct Row {
+    windows: Range<usize>,
+    height: f32,
+}
+
+impl JustifiedRows {
+    /// Splits the windows in `num_rows` rows with a similar total aspect ratio.
+    fn break_rows(aspects: &[f32], num_rows: usize) -> Vec<Range<usize>> {
+        let total: f32 = aspects.iter().sum();
+        let target = total / num_rows as f32;
+
+        let mut rows = Vec::with_capacity(num_rows);
+        let mut start = 0;
+        let mut cumulative = 0.0;
+        for (index, aspect) in aspects.iter().enumerate() {
+            let row_end = target * (rows.len() + 1) as f32;
+            if index > start && cumulative + aspect * 0.5 > row_end && rows.len() + 1 < num_rows {
+                rows.push(start..index);
+                start = index;
+            }
+            cumulative += aspect;
+        }
+        rows.push(start..aspects.len());
+        rows
+    }
+
+    /// Computes the height of each row, shrinking all of them if they don't fit
+    /// vertically.
+    fn row_heights(
+        windows: &[WindowGeometry],
+        aspects: &[f32],
+        breaks: Vec<Range<usize>>,
+        output: Rect,
+        options: &LayoutOptions,
+    ) -> Vec<Row> {
+        let mut rows: Vec<Row> = breaks
+            .into_iter()
+            .map(|range| {
+                let aspect: f32 = aspects[range.clone()].iter().sum();
+                let spacing = options.spacing * (range.len() - 1) as f32;
+                let justified = (output.width - spacing).max(0.0) / aspect;
+                let natural = windows[range.clone()]
+                    .iter()
+                    .map(|window| window.height * options.max_scale)
+                    .fold(f32::MAX, f32::min);
+                Row {
+                    windows: range,
+                    height: justified.min(natural),
+                }
+            })
+            .collect();
+
+        // captions don't shrink with the rows
+        let spacing = options.spacing * (rows.len() - 1) as f32;
+        let captions = options.caption_height * rows.len() as f32;
+        let total: f32 = rows.iter().map(|row| row.height).sum();
+        let available = (output.height - spacing - captions).max(0.0);
+        if total > available {
+            let factor = available / total;
+            for row in rows.iter_mut() {
+                row.height *= factor;
+            }
+        }
+        rows
+    }
+}
+
+impl LayoutStrategy for JustifiedRows {
+    fn layout(
+        &self,
+        windows: &[WindowGeometry],
+        area: &LayoutArea,
+        options: &LayoutOptions,
+    ) -> Vec<Placement> {
+        if windows.is_empty() {
+            return Vec::new();
+        }
+        let output = options.inset(area).largest_free_rect();
+        let aspects: Vec<f32> = windows
+            .iter()
+            .map(|window| window.width / window.height)
+            .collect();
+
+        // keep the number of rows covering the largest area with thumbnails
+        let mut best: Option<(f32, Vec<Row>)> = None;
+        for num_rows in 1..=windows.len() {
+            let breaks = Self::break_rows(&aspects, num_rows);
+            if breaks.len() < num_rows {
+                break;
+            }
+            let rows = Self::row_heights(windows, &aspects, breaks, output, options);
+            let area: f32 = rows
+                .iter()
+                .map(|row| {
+                    let aspect: f32 = aspects[row.windows.clone()].iter().sum();
+                    aspect * row.height * row.height
+                })
+                .sum();
+            let better = match &best {
+                Some((best_area, _)) => area > *best_area,
+                None => true,
+            };
+            if better {
+                best = Some((area, rows));
+            }
+        }
+        let (_, rows) = best.unwrap();
+
+        let spacing = options.spacing * (rows.len() - 1) as f32;
+        let captions = options.caption_height * rows.len() as f32;
+        let total_height: f32 = rows.iter().map(|row| row.height).sum::<f32>() + spacing + captions;
+        let mut y = output.y + (output.height - total_height).max(0.0) * 0.5;
+
+        let mut placements = Vec::with_capacity(windows.len());
+        for row in rows {
+            let aspect: f32 = aspects[row.windows.clone()].iter().sum();
+            let row_width = aspect * row.height + options.spacing * (row.windows.len() - 1) as f32;
+            let mut x = output.x + (output.width - row_width).max(0.0) * 0.5;
+            for window in &windows[row.windows] {
+                let scale = row.height / window.height;
+                placements.push(Placement::new(window.id, x, y, scale));
+                x += window.width * scale + options.spacing;
+            }
+            y += row.height + options.caption_height + options.spacing;
+        }
+        placements
+    }
+}
@@ -0,0 +1,108 @@
+use super::{LayoutArea, LayoutOptions, LayoutStrategy, Placement, Rect};
+use crate::window::WindowGeometry;
+
+/// Maximum number of times the layout is run on a taller output.
+const MAX_PASSES: usize = 8;
+
+/// Runs a strategy on an output extended downwards until no thumbnail is
+/// smaller than `min_thumbnail_size`, so the overview can be scrolled instead
+/// of shrinking windows until they are unreadable. Reserved space along the
+/// bottom of the output is kept at the bottom of the content, see
+/// [`LayoutArea::with_height`].
+pub struct Scrollable<'a> {
+    pub strategy: &'a dyn LayoutStrategy,
+    /// Minimum length of the longer side of each thumbnail, in pixels.
+    pub min_thumbnail_size: f32,
+}
+
+/// Outcome of [`Scrollable::layout_scrollable`].
+#[derive(Clone, Debug)]
+pub struct ScrollableLayout {
+    pub placements: Vec<Placement>,
+    /// Height of the laid out content, at least the area height.
+    pub content_height: f32,
+}
+
+impl Scrollable<'_> {
+    /// Size of the smallest thumbnail in `placements`.
+    fn smallest_thumbnail(&self, windows: &[WindowGeometry], placements: &[Placement]) -> f32 {
+        placements
+            .iter()
+            .filter_map(|placement| {
+                let window = windows.iter().find(|window| window.id == placement.id)?;
+                Some(window.width.max(window.height) * placement.scale)
+            })
+            .fold(f32::MAX, f32::min)
+    }
+
+    pub fn layout_scrollable(
+        &self,
+        windows: &[WindowGeometry],
+        area: &LayoutArea,
+        options: &LayoutOptions,
+    ) -> ScrollableLayout {
+        let output = area.rect;
+        let mut height = output.height;
+        // height `placements` were laid out in
+        let mut laid_out = height;
+        let mut placements = self.strategy.layout(windows, area, options);
+        let mut smallest = self.smallest_thumbnail(windows, &placements);
+        for _ in 0..MAX_PASSES {
+            if placements.is_empty() || smallest >= self.min_thumbnail_size {
+                break;
+            }
+            // thumbnails grow roughly linearly with the output height
+            height *= (self.min_thumbnail_size / smallest).max(1.1);
+            let taller = self
+                .strategy
+                .layout(windows, &area.with_height(height), options);
+            let taller_smallest = self.smallest_thumbnail(windows, &taller);
+            // the strategy is bound by the output width, scrolling won't help
+            if taller_smallest <= smallest {
+                break;
+            }
+            placements = taller;
+            smallest = taller_smallest;
+            laid_out = height;
+        }
+
+        let slots: Vec<Rect> = placements
+            .iter()
+            .filter_map(|placement| {
+                let window = windows.iter().find(|window| window.id == placement.id)?;
+                let (width, height) = options.slot_size(window, placement.scale);
+                Some(Rect::new(placement.x, placement.y, width, height))
+            })
+            .collect();
+        // the last captions, the margin and the inset under them are
+        // scrolled into view too
+        let bottom = slots
+            .iter()
+            .map(|slot| slot.y + slot.height)
+            .fold(f32::MIN, f32::max)
+            + options.margin
+            + area.insets.bottom;
+        let mut content_height = bottom.max(output.y + output.height) - output.y;
+        // space reserved at the bottom, moved up with it, would cover the
+        // lowest thumbnails
+        let content = area.with_height(content_height);
+        if slots.iter().any(|slot| content.is_blocked(*slot)) {
+            content_height = laid_out;
+        }
+        ScrollableLayout {
+            placements,
+            content_height,
+        }
+    }
+}
+
+impl LayoutStrategy for Scrollable<'_> {
+    fn layout(
+        &self,
+        windows: &[WindowGeometry],
+        area: &LayoutArea,
+        options: &LayoutOptions,
+    ) -> Vec<Placement> {
+        self.layout_scrollable(windows, area, options).placements
+    }
+}
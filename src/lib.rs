@@ -0,0 +1,13 @@
+//! Window overview layouts.
+//!
+//! The library only deals with window geometries and has no dependency on a
+//! display or GPU; the `expose-layers` binary renders the result with
+//! [layers](https://github.com/nongio/layers). The `layers` feature adds
+//! [`window::window_geometry`] to read the geometry of a window from its
+//! layer.
+
+pub mod gesture;
+pub mod layout;
+pub mod packer;
+pub mod snapshot;
+pub mod window;
@@ -1,6 +1,5 @@
-use std::time::Duration;
+use std::{error::Error, time::Duration};
 
-use binpack2d::{bin_new, BinType, Dimension};
 use gl_rs as gl;
 use glutin::{
     event::{Event, WindowEvent},
@@ -9,12 +8,31 @@ use glutin::{
     GlProfile,
 };
 
+use rand::rngs::StdRng;
+
 use layers::{
     prelude::{timing::TimingFunction, *},
     skia::{self, Color4f, ColorType},
     types::Size,
 };
-use rand::Rng;
+
+mod viewer;
+
+use expose_layers::layout::{
+    BinPack, BinPack2, Expose, JustifiedRows, LayoutArea, LayoutOptions, Natural, Normalize,
+    OutputAssignment, Rect,
+};
+use viewer::{
+    apply_layout, demo_area, draw_overlay, strategy_by_name, Cli, Desktop, HeadlessRenderer,
+    Overview, Recorder, ReportingBinPack2, Scene, SimulatedOutputs,
+};
+
+/// Below this size thumbnails are too small to recognize, so the overview
+/// scrolls instead.
+const MIN_THUMBNAIL_SIZE: f32 = 200.0;
+
+const SPACE_WIDTH: i32 = 1000;
+const SPACE_HEIGHT: i32 = 1000;
 
 pub fn draw(canvas: &mut skia::Canvas, width: f32, _height: f32) {
     let mut text_style = skia::textlayout::TextStyle::new();
@@ -44,382 +62,173 @@ pub fn draw(canvas: &mut skia::Canvas, width: f32, _height: f32) {
     paragraph.paint(canvas, (0.0, 0.0));
 }
 
-fn expose(windows: &mut Vec<Layer>, space_width: f32, space_height: f32) {
-    let num_windows = windows.len();
-    let num_cols = (num_windows as f32).sqrt().ceil() as usize;
-    let num_rows = num_cols;
-
-    let cell_width = space_width / num_cols as f32;
-    let cell_height = space_height / num_rows as f32;
-
-    let mut cell_assigned = vec![false; num_rows * num_cols];
-
-    for window in windows.iter_mut() {
-        let size = window.size();
-        let (scale_x, scale_y, window_width, window_height) = match (size.width, size.height) {
-            (taffy::Dimension::Points(width), taffy::Dimension::Points(height)) => {
-                let scale_x = cell_width / width;
-                let scale_y = cell_height / height;
-                (scale_x, scale_y, width, height)
-            }
-            _ => (1.0, 1.0, 0.0, 0.0),
-        };
-        let scale = scale_x.min(scale_y);
-
-        let mut min_distance = f32::MAX;
-        let mut closest_cell = (0, 0);
-
-        let position = window.position();
-        for row in 0..num_rows {
-            for col in 0..num_cols {
-                if cell_assigned[row * num_cols + col] {
-                    continue;
-                }
-
-                let cell_center_x = col as f32 * cell_width + cell_width * 0.5;
-                let cell_center_y = row as f32 * cell_height + cell_height * 0.5;
-
-                let distance = ((position.x - cell_center_x).powi(2)
-                    + (position.y - cell_center_y).powi(2))
-                .sqrt();
-
-                if distance < min_distance {
-                    min_distance = distance;
-                    closest_cell = (row, col);
-                }
-            }
-        }
-
-        let (row, col) = closest_cell;
-        cell_assigned[row * num_cols + col] = true;
-
-        let x = col as f32 * cell_width + cell_width * 0.5 - window_width * 0.5 * scale;
-        let y = row as f32 * cell_height + cell_height * 0.5 - window_height * 0.5 * scale;
-
-        window.set_scale((scale, scale), Some(Transition::default()));
-        window.set_position((x, y), Some(Transition::default()));
-    }
-}
-
-fn expose_step(windows: &mut Vec<Layer>, space_width: f32, space_height: f32, step: i32) {
-    let step = step as f32 / 100.0;
-    let num_windows = windows.len();
-    let num_cols = (num_windows as f32).sqrt().ceil() as usize;
-    let num_rows = num_cols;
-
-    let cell_width = space_width / num_cols as f32;
-    let cell_height = space_height / num_rows as f32;
-
-    let mut cell_assigned = vec![false; num_rows * num_cols];
-
-    for window in windows.iter_mut() {
-        let size = window.size();
-        let (scale_x, scale_y, window_width, window_height) = match (size.width, size.height) {
-            (taffy::Dimension::Points(width), taffy::Dimension::Points(height)) => {
-                let scale_x = cell_width / width;
-                let scale_y = cell_height / height;
-                (scale_x, scale_y, width, height)
-            }
-            _ => (1.0, 1.0, 0.0, 0.0),
-        };
-
-        let mut min_distance = f32::MAX;
-        let mut closest_cell = (0, 0);
-
-        let position = window.position();
-        for row in 0..num_rows {
-            for col in 0..num_cols {
-                if cell_assigned[row * num_cols + col] {
-                    continue;
-                }
-
-                let cell_center_x = col as f32 * cell_width + cell_width * 0.5;
-                let cell_center_y = row as f32 * cell_height + cell_height * 0.5;
-
-                let distance = ((position.x - cell_center_x).powi(2)
-                    + (position.y - cell_center_y).powi(2))
-                .sqrt();
-
-                if distance < min_distance {
-                    min_distance = distance;
-                    closest_cell = (row, col);
-                }
-            }
-        }
+/// Adds the layer the windows are laid out in to the scene.
+fn new_root(engine: &LayersEngine) -> Layer {
+    let root = engine.new_layer();
 
-        let (row, col) = closest_cell;
-        cell_assigned[row * num_cols + col] = true;
-
-        let scale = window.scale().x;
-        let to_scale = scale_x.min(scale_y);
-        let scale = scale.interpolate(&to_scale, step);
-        let x = col as f32 * cell_width + cell_width * 0.5 - window_width * 0.5 * scale;
-        let y = row as f32 * cell_height + cell_height * 0.5 - window_height * 0.5 * scale;
-        let x = position.x.interpolate(&x, step);
-        let y = position.y.interpolate(&y, step);
-
-        window.set_scale((scale, scale), Some(Transition::default()));
-        window.set_position((x, y), Some(Transition::default()));
-    }
+    root.set_size(layers::types::Size::points(2000.0, 2000.0), None);
+    root.set_background_color(
+        PaintColor::Solid {
+            color: Color::new_rgba255(180, 180, 180, 255),
+        },
+        None,
+    );
+    root.set_border_corner_radius(10.0, None);
+    root.set_layout_style(taffy::Style {
+        // display: taffy::Display::Flex,
+        // align_content: Some(taffy::AlignContent::Center),
+        // align_items: Some(taffy::AlignItems::Center),
+        // justify_content: Some(taffy::JustifyContent::Center),
+        ..Default::default()
+    });
+    engine.scene_add_layer(root.clone());
+    root
 }
 
-fn normalize(windows: &mut Vec<Layer>, space_width: f32, space_height: f32) {
-    let num_windows = windows.len();
-    let num_cols = (num_windows as f32).sqrt().ceil() as usize;
-    let num_rows = num_cols;
-
-    let window_width = space_width / num_cols as f32;
-    let window_height = space_height / num_rows as f32;
-
-    for (index, window) in windows.iter_mut().enumerate() {
-        let row = index / num_cols;
-        let col = index % num_cols;
-
-        let x = col as f32 * window_width;
-        let y = row as f32 * window_height;
-        let size = window.size();
-
-        window.set_scale((1.0, 1.0), Some(Transition::default()));
-        window.set_position(
-            (50.0 * index as f32, 50.0 * index as f32),
-            Some(Transition::default()),
-        );
-    }
+/// Windows of `--scene`, or random ones drawn from `rng`.
+fn load_desktop(
+    cli: &Cli,
+    engine: &LayersEngine,
+    rng: &mut StdRng,
+) -> Result<Desktop, Box<dyn Error>> {
+    let scene = match &cli.scene_file {
+        Some(path) => Scene::load(path)?,
+        None => cli.scene.scene(rng),
+    };
+    Desktop::new(engine, scene, cli.workspace)
 }
 
-pub struct Bin {
-    width: f32,
-    height: f32,
-    windows: Vec<Layer>,
+/// Scene of the headless modes.
+struct Headless {
+    engine: LayersEngine,
+    desktop: Desktop,
+    overview: Overview,
+    area: LayoutArea,
+    renderer: HeadlessRenderer,
 }
 
-impl Bin {
-    pub fn new(width: f32, height: f32) -> Self {
-        Bin {
-            width,
-            height,
-            windows: Vec::new(),
-        }
+impl Headless {
+    fn new(cli: &Cli) -> Result<Self, Box<dyn Error>> {
+        let output = Rect::new(
+            0.0,
+            0.0,
+            SPACE_WIDTH as f32 * 2.0,
+            SPACE_HEIGHT as f32 * 2.0,
+        );
+        let engine = LayersEngine::new(output.width, output.height);
+        let root_layer = new_root(&engine);
+        let desktop = load_desktop(cli, &engine, &mut cli.scene.rng())?;
+        let overview = Overview::new(root_layer, LayoutOptions::default(), MIN_THUMBNAIL_SIZE);
+        // computes the layout of the layers
+        engine.update(0.0);
+        Ok(Headless {
+            engine,
+            desktop,
+            overview,
+            area: demo_area(output, false),
+            renderer: HeadlessRenderer::new(output.width as i32, output.height as i32),
+        })
     }
 
-    pub fn add(&mut self, window: Layer) -> bool {
-        let size = window.size();
-        let (window_width, window_height) = match (size.width, size.height) {
-            (taffy::Dimension::Points(width), taffy::Dimension::Points(height)) => (width, height),
-            _ => (0.0, 0.0),
-        };
-        if self.width >= window_width && self.height >= window_height {
-            self.windows.push(window);
-            true
-        } else {
-            false
+    /// Shows the overview of the command line strategy, or `default`.
+    fn show(&mut self, cli: &Cli, default: &str) {
+        let name = cli.strategy.as_deref().unwrap_or(default);
+        if let Some(strategy) = strategy_by_name(name) {
+            self.overview
+                .show(&self.desktop.windows, strategy, &self.area);
         }
     }
 
-    pub fn can_fit(&self, window: &Layer) -> bool {
-        let size = window.size();
-        let (window_width, window_height) = match (size.width, size.height) {
-            (taffy::Dimension::Points(width), taffy::Dimension::Points(height)) => (width, height),
-            _ => (0.0, 0.0),
-        };
-        self.width >= window_width && self.height >= window_height
+    /// Advances the animations by one fixed timestep.
+    fn step(&mut self, dt: f32) {
+        self.overview.tick(&self.engine, dt, &self.area);
+        self.engine.update(dt);
     }
 
-    pub fn empty_space_after_insertion(&self, window: &Layer) -> f32 {
-        let size = window.size();
-        let (window_width, window_height) = match (size.width, size.height) {
-            (taffy::Dimension::Points(width), taffy::Dimension::Points(height)) => (width, height),
-            _ => (0.0, 0.0),
-        };
-        (self.width - window_width) * (self.height - window_height)
+    fn render(&mut self) -> image::RgbaImage {
+        self.renderer.draw_scene(&self.engine);
+        draw_overlay(self.renderer.canvas(), &self.area, false, &self.overview);
+        self.renderer.image()
     }
 }
-pub fn bin_pack(windows: &mut Vec<Layer>, bin_width: f32, bin_height: f32) {
-    // Sort windows in decreasing order of size
-    windows.sort_by(|a, b| {
-        let size_a = a.size();
-        let size_b = b.size();
-        let area_a = match (size_a.width, size_a.height) {
-            (taffy::Dimension::Points(width), taffy::Dimension::Points(height)) => width * height,
-            _ => 0.0,
-        };
-        let area_b = match (size_b.width, size_b.height) {
-            (taffy::Dimension::Points(width), taffy::Dimension::Points(height)) => width * height,
-            _ => 0.0,
-        };
-        area_b.partial_cmp(&area_a).unwrap()
-    });
-
-    let mut bins: Vec<Bin> = Vec::new();
-
-    for window in windows.iter() {
-        let mut best_fit = None;
-        let mut min_empty_space = f32::MAX;
-
-        for (i, bin) in bins.iter_mut().enumerate() {
-            if bin.can_fit(window) {
-                let empty_space = bin.empty_space_after_insertion(window);
-                if empty_space < min_empty_space {
-                    best_fit = Some(i);
-                    min_empty_space = empty_space;
-                }
-            }
-        }
 
-        if let Some(i) = best_fit {
-            bins[i].add(window.clone());
-        } else {
-            let mut bin = Bin::new(bin_width, bin_height);
-            bin.add(window.clone());
-            bins.push(bin);
+/// Renders the demo scene on the CPU and writes it to `cli.out`, once the
+/// overview of `--strategy`, if any, has been animated for `--duration`.
+fn run_headless(cli: &Cli) -> Result<(), Box<dyn Error>> {
+    let mut headless = Headless::new(cli)?;
+    if cli.strategy.is_some() {
+        headless.show(cli, "expose");
+        for _ in 1..cli.frames() {
+            headless.step(cli.timestep());
         }
     }
-   
-    let mut max_height_in_row = 0.0;
-
-   
-    let total_window_area: f32 = {
-        windows
-            .iter()
-            .map(|window| {
-                let size = window.size();
-                match (size.width, size.height) {
-                    (taffy::Dimension::Points(width), taffy::Dimension::Points(height)) => {
-                        width * height
-                    }
-                    _ => 0.0,
-                }
-            })
-            .sum()
-    };
+    headless.render().save(&cli.out)?;
+    println!("wrote {}", cli.out.display());
+    Ok(())
+}
 
-    let total_bin_area = bin_width * bin_height * bins.len() as f32;
-    let scale_factor = (total_bin_area / total_window_area).sqrt();
-
-    for bin in &mut bins {
-        println!("bin: {}x{}", bin.width, bin.height);
-        let mut x = 0.0;
-        let mut y = 0.0;
-        for window in &mut bin.windows {
-            let size = window.size();
-            let (window_width, window_height) = match (size.width, size.height) {
-                (taffy::Dimension::Points(width), taffy::Dimension::Points(height)) => {
-                    (width, height)
-                }
-                _ => (0.0, 0.0),
-            };
-            window.set_position((x, y), Some(Transition::default()));
-            window.set_scale((scale_factor, scale_factor), Some(Transition::default()));
-            let window_height = window_height * scale_factor;
-            let window_width = window_width * scale_factor;
-
-            if window_height > max_height_in_row {
-                max_height_in_row = window_height;
-            }
-            if x + window_width * 2.0 > bin.width {
-                x = 0.0;
-                y += max_height_in_row;
-                max_height_in_row = 0.0;
-            } else {
-                x += window_width;
-            }
-        }
+/// Records the demo scene entering the overview of `--strategy`, expose by
+/// default, one frame per timestep.
+fn run_record(cli: &Cli) -> Result<(), Box<dyn Error>> {
+    let mut headless = Headless::new(cli)?;
+    let size = (SPACE_WIDTH as u32 * 2, SPACE_HEIGHT as u32 * 2);
+    let mut recorder = Recorder::create(
+        cli.record.as_deref(),
+        cli.frames_dir.as_deref(),
+        size,
+        cli.frames(),
+        cli.fps,
+    )?;
+    recorder.push(headless.render())?;
+    headless.show(cli, "expose");
+    for _ in 1..cli.frames() {
+        headless.step(cli.timestep());
+        recorder.push(headless.render())?;
+    }
+    recorder.finish()?;
+    if let Some(record) = &cli.record {
+        println!("wrote {}", record.display());
     }
+    Ok(())
 }
 
-pub fn bin_pack2(windows: &mut Vec<Layer>, bin_width: f32, bin_height: f32) {
-    let total_window_area: f32 = {
-        windows
-            .iter()
-            .map(|window| {
-                let size = window.size();
-                match (size.width, size.height) {
-                    (taffy::Dimension::Points(width), taffy::Dimension::Points(height)) => {
-                        width * height
-                    }
-                    _ => 0.0,
-                }
-            })
-            .sum()
+fn main() {
+    let cli = match Cli::parse(std::env::args().skip(1)) {
+        Ok(cli) => cli,
+        Err(message) => {
+            eprintln!("{message}");
+            std::process::exit(2);
+        }
     };
-
-    let total_bin_area = bin_width * bin_height;
-    let mut scale_factor = (total_bin_area / total_window_area).sqrt();
-    let mut items_to_place = Vec::new();
-    for window in windows.iter() {
-        let size = window.size();
-        let (window_width, window_height) = match (size.width, size.height) {
-            (taffy::Dimension::Points(width), taffy::Dimension::Points(height)) => (width, height),
-            _ => (0.0, 0.0),
-        };
-        let id = window.id().unwrap();
-        let id:usize = id.0.into();
-        let dimension = Dimension::with_id(id as isize, (window_width * scale_factor) as i32, (window_height * scale_factor) as i32, 20);
-        items_to_place.push(dimension);
-    }
-
-    let mut bin = bin_new(BinType::MaxRects, bin_width as i32, bin_height as i32);
-    let (mut inserted, mut rejected) = bin.insert_list(&items_to_place);
-    let mut tries = 0;
-    while (!rejected.is_empty() || inserted.len() != windows.len()) && tries < 40 {
-        scale_factor *= 0.99;
-        scale_factor = scale_factor.max(0.1);
-        let mut items_to_place = Vec::new();
-        for window in windows.iter() {
-            let size = window.size();
-            let (window_width, window_height) = match (size.width, size.height) {
-                (taffy::Dimension::Points(width), taffy::Dimension::Points(height)) => (width, height),
-                _ => (0.0, 0.0),
-            };
-            let id = window.id().unwrap();
-            let id:usize = id.0.into();
-            let dimension = Dimension::with_id(id as isize, (window_width * scale_factor) as i32, (window_height * scale_factor) as i32, 20);
-            items_to_place.push(dimension);
-            // println!("{} {} {}", id, (window_width*scale_factor) as i32, (window_height*scale_factor) as i32);
+    match &cli.scene_file {
+        Some(path) => println!("scene {}", path.display()),
+        None => {
+            let scene = &cli.scene;
+            println!(
+                "seed {}: {} windows, width {}, height {}, x {}, y {}",
+                scene.seed, scene.count, scene.width, scene.height, scene.x, scene.y
+            );
         }
-        bin.clear();
-        (inserted, rejected) = bin.insert_list(&items_to_place);
-        // println!("items placed: {}/{} scale {}", inserted.len(), windows.len(), scale_factor);
-        tries += 1;
     }
-    // println!("rejected: {}", rejected.len());
-    // println!("inserted: {}/{}", inserted.len(), windows.len());
-    for (index, window) in windows.iter_mut().enumerate() {
-        let id = window.id().unwrap();
-        let id:usize = id.0.into();
-        if let Some(rect) = bin.find_by_id(id as isize) {
-            let x = rect.x();
-            let y = rect.y();
-            let width = rect.width();
-            let height = rect.height();
-            let size = window.size();
-            let (window_width, window_height) = match (size.width, size.height) {
-                (taffy::Dimension::Points(width), taffy::Dimension::Points(height)) => (width, height),
-                _ => (0.0, 0.0),
-            };
-            let scale_x = width as f32 / window_width;
-            let scale_y = height as f32 / window_height;
-            let scale = scale_x.min(scale_y).min(1.0);
-
-            // println!("{}, {}, {}", scale, x, y);
-            window.set_position((x as f32, y as f32), Some(Transition::default()));
-            window.set_scale((scale, scale), Some(Transition::default()));
-        } else {
-            println!("{} not found", id);
+    let headless = if cli.record.is_some() || cli.frames_dir.is_some() {
+        Some(run_record(&cli))
+    } else if cli.headless {
+        Some(run_headless(&cli))
+    } else {
+        None
+    };
+    if let Some(result) = headless {
+        if let Err(err) = result {
+            eprintln!("{err}");
+            std::process::exit(1);
         }
+        return;
     }
 
-}
-fn main() {
     type WindowedContext = glutin::ContextWrapper<glutin::PossiblyCurrent, glutin::window::Window>;
 
     use winit::dpi::LogicalSize;
 
-    const NUM_WINDOWS: u8 = 10;
-
-    const SPACE_WIDTH: i32 = 1000;
-    const SPACE_HEIGHT: i32 = 1000;
-
     let size: LogicalSize<i32> = LogicalSize::new(SPACE_WIDTH, SPACE_HEIGHT);
 
     let events_loop = EventLoop::new();
@@ -463,69 +272,29 @@ fn main() {
     }
     let env = Env { windowed_context };
     let engine = LayersEngine::new(SPACE_WIDTH as f32 * 2.0, SPACE_HEIGHT as f32 * 2.0);
-    let root_layer = engine.new_layer();
-
-    root_layer.set_size(layers::types::Size::points(2000.0, 2000.0), None);
-    root_layer.set_background_color(
-        PaintColor::Solid {
-            color: Color::new_rgba255(180, 180, 180, 255),
-        },
-        None,
-    );
-    root_layer.set_border_corner_radius(10.0, None);
-    root_layer.set_layout_style(taffy::Style {
-        // display: taffy::Display::Flex,
-        // align_content: Some(taffy::AlignContent::Center),
-        // align_items: Some(taffy::AlignItems::Center),
-        // justify_content: Some(taffy::JustifyContent::Center),
-        ..Default::default()
-    });
-    engine.scene_add_layer(root_layer.clone());
-
-    let mut windows = Vec::new();
-
-    let mut rng = rand::thread_rng();
-    for i in 0..NUM_WINDOWS {
-        let window = engine.new_layer();
-        let width = rng.gen_range(200.0..1000.0);
-        let height = rng.gen_range(300.0..1000.0);
-        window.set_size(layers::types::Size::points(width, height), None);
-        let r = rng.gen_range(0..255);
-        let g = rng.gen_range(0..255);
-        let b = rng.gen_range(0..255);
-
-        window.set_background_color(
-            PaintColor::Solid {
-                color: Color::new_rgba255(r, g, b, 255),
-            },
-            None,
-        );
-        window.set_border_width(1.0, None);
-        window.set_border_color(
-            PaintColor::Solid {
-                color: Color::new_rgba255(0, 0, 0, 255),
-            },
-            None,
-        );
-        window.set_layout_style(taffy::Style {
-            position: taffy::Position::Absolute,
-            ..Default::default()
-        });
-        let x = rng.gen_range(0.0..(SPACE_WIDTH as f32 * 2.0));
-        let y = rng.gen_range(0.0..SPACE_HEIGHT as f32 * 2.0);
-
-        window.set_position((x, y), None);
-        window.set_border_corner_radius(BorderRadius::new_single(20.0), None);
-        engine.scene_add_layer(window.clone());
-
-        windows.push(window);
-    }
+    let root_layer = new_root(&engine);
+
+    let mut rng = cli.scene.rng();
+    let mut desktop = match load_desktop(&cli, &engine, &mut rng) {
+        Ok(desktop) => desktop,
+        Err(err) => {
+            eprintln!("{err}");
+            std::process::exit(1);
+        }
+    };
 
+    let options = LayoutOptions::default();
     let instant = std::time::Instant::now();
     let mut update_frame = 0;
     let mut draw_frame = -1;
     let last_instant = instant;
-    let mut step = 0;
+    let mut overview = Overview::new(root_layer.clone(), options, MIN_THUMBNAIL_SIZE);
+    let mut output = Rect::new(0.0, 0.0, 2000.0, 2000.0);
+    // how windows are spread over the simulated outputs, `None` for a single one
+    let mut outputs: Option<OutputAssignment> = None;
+    // whether a panel, notch and dock are simulated
+    let mut reserved = false;
+    let mut area = demo_area(output, reserved);
     events_loop.run(move |event, _, control_flow| {
         let now = std::time::Instant::now();
         let dt = (now - last_instant).as_secs_f32();
@@ -550,6 +319,9 @@ fn main() {
                     );
                     let _transition = root_layer
                         .set_size(Size::points(size.width as f32, size.height as f32), None);
+                    output = Rect::new(0.0, 0.0, size.width as f32, size.height as f32);
+                    area = demo_area(output, reserved);
+                    overview.relayout(&desktop.windows, &area);
                     env.windowed_context.window().request_redraw();
                 }
                 WindowEvent::KeyboardInput {
@@ -571,15 +343,163 @@ fn main() {
                                 }
                             }
                             winit::event::VirtualKeyCode::A => {
-                                bin_pack2(&mut windows, 2000.0, 2000.0);
+                                if input.state == winit::event::ElementState::Pressed {
+                                    let bin_pack2 = BinPack2 {
+                                        compare: true,
+                                        balance: true,
+                                        ..Default::default()
+                                    };
+                                    overview.show(
+                                        &desktop.windows,
+                                        SimulatedOutputs::wrap(
+                                            Box::new(ReportingBinPack2(bin_pack2)),
+                                            outputs,
+                                        ),
+                                        &area,
+                                    );
+                                }
+                            }
+                            winit::event::VirtualKeyCode::B => {
+                                if input.state == winit::event::ElementState::Pressed {
+                                    overview.show(
+                                        &desktop.windows,
+                                        SimulatedOutputs::wrap(Box::new(BinPack), outputs),
+                                        &area,
+                                    );
+                                }
+                            }
+                            winit::event::VirtualKeyCode::E => {
+                                if input.state == winit::event::ElementState::Pressed {
+                                    overview.show(
+                                        &desktop.windows,
+                                        SimulatedOutputs::wrap(
+                                            Box::new(Expose::default()),
+                                            outputs,
+                                        ),
+                                        &area,
+                                    );
+                                }
                             }
 
+                            winit::event::VirtualKeyCode::J => {
+                                if input.state == winit::event::ElementState::Pressed {
+                                    overview.show(
+                                        &desktop.windows,
+                                        SimulatedOutputs::wrap(Box::new(JustifiedRows), outputs),
+                                        &area,
+                                    );
+                                }
+                            }
+                            winit::event::VirtualKeyCode::N => {
+                                if input.state == winit::event::ElementState::Pressed {
+                                    overview.show(
+                                        &desktop.windows,
+                                        SimulatedOutputs::wrap(
+                                            Box::new(Natural::default()),
+                                            outputs,
+                                        ),
+                                        &area,
+                                    );
+                                }
+                            }
+                            winit::event::VirtualKeyCode::O => {
+                                if input.state == winit::event::ElementState::Pressed {
+                                    // single output, then two simulated ones by overlap, then balanced
+                                    outputs = match outputs {
+                                        None => Some(OutputAssignment::Overlap),
+                                        Some(OutputAssignment::Overlap) => {
+                                            Some(OutputAssignment::Balance)
+                                        }
+                                        Some(OutputAssignment::Balance) => None,
+                                    };
+                                    println!("outputs: {:?}", outputs);
+                                    env.windowed_context.window().request_redraw();
+                                }
+                            }
+                            winit::event::VirtualKeyCode::R => {
+                                if input.state == winit::event::ElementState::Pressed {
+                                    reserved = !reserved;
+                                    area = demo_area(output, reserved);
+                                    overview.relayout(&desktop.windows, &area);
+                                    env.windowed_context.window().request_redraw();
+                                }
+                            }
+                            winit::event::VirtualKeyCode::W => {
+                                if input.state == winit::event::ElementState::Pressed {
+                                    let mut description =
+                                        cli.scene.description(desktop.scene.next_id(), &mut rng);
+                                    description.workspace = desktop.workspace;
+                                    match desktop.add(&engine, description) {
+                                        Ok(Some(window)) => {
+                                            overview.insert_window(&desktop.windows, &window)
+                                        }
+                                        Ok(None) => {}
+                                        Err(err) => eprintln!("{err}"),
+                                    }
+                                }
+                            }
+                            winit::event::VirtualKeyCode::X => {
+                                if input.state == winit::event::ElementState::Pressed {
+                                    if let Some(window) = desktop.pop() {
+                                        overview.remove_window(&window);
+                                    }
+                                }
+                            }
+                            winit::event::VirtualKeyCode::D => {
+                                if input.state == winit::event::ElementState::Pressed {
+                                    // windows are saved where they are outside of the overview
+                                    let geometry = overview.desktop_geometry(&desktop.windows);
+                                    match desktop.capture(&geometry).save(&cli.scene_out) {
+                                        Ok(()) => println!("wrote {}", cli.scene_out.display()),
+                                        Err(err) => eprintln!("{err}"),
+                                    }
+                                }
+                            }
+                            winit::event::VirtualKeyCode::C => {
+                                if input.state == winit::event::ElementState::Pressed {
+                                    apply_layout(&desktop.windows, &Normalize, &area, &options);
+                                }
+                            }
                             winit::event::VirtualKeyCode::S => {
-                                normalize(&mut windows, 2000.0, 2000.0);
+                                if input.state == winit::event::ElementState::Pressed {
+                                    overview.restore(&desktop.windows, &area);
+                                }
+                            }
+                            winit::event::VirtualKeyCode::Return
+                            | winit::event::VirtualKeyCode::Back => {
+                                if input.state == winit::event::ElementState::Pressed {
+                                    let delta = if keycode == winit::event::VirtualKeyCode::Return {
+                                        0.05
+                                    } else {
+                                        -0.05
+                                    };
+                                    overview.scrub(
+                                        &desktop.windows,
+                                        Box::new(Expose::default()),
+                                        &area,
+                                        delta,
+                                    );
+                                }
+                            }
+                            winit::event::VirtualKeyCode::Left => {
+                                if input.state == winit::event::ElementState::Pressed {
+                                    overview.previous_page(&area);
+                                }
+                            }
+                            winit::event::VirtualKeyCode::Right => {
+                                if input.state == winit::event::ElementState::Pressed {
+                                    overview.next_page(&area);
+                                }
                             }
-                            winit::event::VirtualKeyCode::Return => {
-                                step += 2;
-                                expose_step(&mut windows, 2000.0, 2000.0, step);
+                            winit::event::VirtualKeyCode::Up => {
+                                if input.state == winit::event::ElementState::Pressed {
+                                    overview.commit(&desktop.windows, &area);
+                                }
+                            }
+                            winit::event::VirtualKeyCode::Down => {
+                                if input.state == winit::event::ElementState::Pressed {
+                                    overview.cancel(&desktop.windows);
+                                }
                             }
 
                             winit::event::VirtualKeyCode::Escape => {
@@ -595,6 +515,30 @@ fn main() {
                     // _mouse_y = position.y;
                 }
 
+                WindowEvent::MouseWheel { delta, .. } => {
+                    // a wheel notch flips a page horizontally, or scrubs 5% of the gesture;
+                    // trackpads keep scrolling with momentum once lifted
+                    let (delta_x, delta_y, momentum) = match delta {
+                        winit::event::MouseScrollDelta::LineDelta(x, y) => {
+                            (x * 120.0, y * 25.0, false)
+                        }
+                        winit::event::MouseScrollDelta::PixelDelta(position) => {
+                            (position.x as f32, position.y as f32, true)
+                        }
+                    };
+                    if delta_x.abs() > delta_y.abs() {
+                        overview.scroll_pages(delta_x, &area);
+                    } else if overview.scrollable() {
+                        overview.scroll(delta_y, momentum, &area);
+                    } else {
+                        overview.scrub(
+                            &desktop.windows,
+                            Box::new(Expose::default()),
+                            &area,
+                            delta_y / 500.0,
+                        );
+                    }
+                }
                 WindowEvent::MouseInput { state: _, .. } => {}
                 _ => (),
             },
@@ -604,6 +548,7 @@ fn main() {
                 if update_frame != frame_number {
                     update_frame = frame_number;
                     let dt = 0.016;
+                    overview.tick(&engine, dt, &area);
                     let needs_redraw = engine.update(dt);
                     if needs_redraw {
                         env.windowed_context.window().request_redraw();
@@ -629,14 +574,14 @@ fn main() {
                         paint.set_stroke(true);
                         paint.set_stroke_width(2.0);
 
-                        let num_cols = (NUM_WINDOWS as f32).sqrt().ceil() as usize;
+                        let num_cols = (desktop.windows.len() as f32).sqrt().ceil() as usize;
                         let num_rows = num_cols;
 
                         let window_width = SPACE_WIDTH as f32 * 2.0 / num_cols as f32;
                         let window_height = SPACE_HEIGHT as f32 * 2.0 / num_rows as f32;
-                        for index in 0..NUM_WINDOWS {
-                            let row = index as usize / num_cols;
-                            let col = index as usize % num_cols;
+                        for index in 0..desktop.windows.len() {
+                            let row = index / num_cols;
+                            let col = index % num_cols;
 
                             let x = col as f32 * window_width;
                             let y = row as f32 * window_height;
@@ -644,6 +589,8 @@ fn main() {
                             // canvas.draw_rect(rect, &paint);
                         }
 
+                        draw_overlay(canvas, &area, outputs.is_some(), &overview);
+
                         // draw damage
                         // let mut paint = skia::Paint::new(Color4f::new(1.0, 0.0, 0.0, 1.0), None);
                         // paint.set_stroke(true);
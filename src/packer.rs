@@ -0,0 +1,136 @@
+use crate::layout::Rect;
+
+/// Rectangle packer with sub-pixel coordinates.
+///
+/// The free space is tracked as a list of disjoint rectangles; each insertion
+/// goes into the free rectangle it fits best, and what is left of it is split
+/// in two along the shorter leftover axis (guillotine split).
+#[derive(Clone, Debug)]
+pub struct Bin {
+    width: f32,
+    height: f32,
+    free: Vec<Rect>,
+    placed: Vec<(usize, Rect)>,
+}
+
+impl Bin {
+    pub fn new(width: f32, height: f32) -> Self {
+        Bin {
+            width,
+            height,
+            free: vec![Rect::new(0.0, 0.0, width, height)],
+            placed: Vec::new(),
+        }
+    }
+
+    pub fn width(&self) -> f32 {
+        self.width
+    }
+
+    pub fn height(&self) -> f32 {
+        self.height
+    }
+
+    /// Ids and rectangles of the items added so far, in insertion order.
+    pub fn placed(&self) -> &[(usize, Rect)] {
+        &self.placed
+    }
+
+    /// Free rectangles left, disjoint from each other.
+    pub fn free(&self) -> &[Rect] {
+        &self.free
+    }
+
+    /// Marks `rect` as occupied, so nothing is placed over it. Each free
+    /// rectangle it intersects is split in the parts above, below, left and
+    /// right of it. Reserving after adding items is fine, as long as `rect`
+    /// doesn't overlap them.
+    pub fn reserve(&mut self, rect: Rect) {
+        let mut free = Vec::with_capacity(self.free.len());
+        for f in self.free.drain(..) {
+            let left = rect.x.max(f.x);
+            let right = (rect.x + rect.width).min(f.x + f.width);
+            let top = rect.y.max(f.y);
+            let bottom = (rect.y + rect.height).min(f.y + f.height);
+            if left >= right || top >= bottom {
+                free.push(f);
+                continue;
+            }
+            let splits = [
+                Rect::new(f.x, f.y, f.width, top - f.y),
+                Rect::new(f.x, bottom, f.width, f.y + f.height - bottom),
+                Rect::new(f.x, top, left - f.x, bottom - top),
+                Rect::new(right, top, f.x + f.width - right, bottom - top),
+            ];
+            free.extend(
+                splits
+                    .into_iter()
+                    .filter(|split| split.width > 0.0 && split.height > 0.0),
+            );
+        }
+        self.free = free;
+    }
+
+    /// Empties the bin, reservations included.
+    pub fn clear(&mut self) {
+        self.free = vec![Rect::new(0.0, 0.0, self.width, self.height)];
+        self.placed.clear();
+    }
+
+    /// Index of the free rectangle leaving the least area once a
+    /// `width x height` item is inserted in it.
+    fn best_free_rect(&self, width: f32, height: f32) -> Option<usize> {
+        self.free
+            .iter()
+            .enumerate()
+            .filter(|(_, free)| free.width >= width && free.height >= height)
+            .min_by(|(_, a), (_, b)| {
+                let a = a.width * a.height;
+                let b = b.width * b.height;
+                a.total_cmp(&b)
+            })
+            .map(|(index, _)| index)
+    }
+
+    pub fn can_fit(&self, width: f32, height: f32) -> bool {
+        self.best_free_rect(width, height).is_some()
+    }
+
+    /// Area of the free rectangle the item would go into that is left empty,
+    /// or `None` if the item doesn't fit.
+    pub fn empty_space_after_insertion(&self, width: f32, height: f32) -> Option<f32> {
+        let free = self.free[self.best_free_rect(width, height)?];
+        Some(free.width * free.height - width * height)
+    }
+
+    /// Inserts an item, returning where it was placed.
+    pub fn add(&mut self, id: usize, width: f32, height: f32) -> Option<Rect> {
+        let index = self.best_free_rect(width, height)?;
+        let free = self.free.swap_remove(index);
+        let rect = Rect::new(free.x, free.y, width, height);
+
+        let leftover_width = free.width - width;
+        let leftover_height = free.height - height;
+        let (right, bottom) = if leftover_width < leftover_height {
+            // split horizontally, the bottom part spans the whole free rect
+            (
+                Rect::new(free.x + width, free.y, leftover_width, height),
+                Rect::new(free.x, free.y + height, free.width, leftover_height),
+            )
+        } else {
+            // split vertically, the right part spans the whole free rect
+            (
+                Rect::new(free.x + width, free.y, leftover_width, free.height),
+                Rect::new(free.x, free.y + height, width, leftover_height),
+            )
+        };
+        for split in [right, bottom] {
+            if split.width > 0.0 && split.height > 0.0 {
+                self.free.push(split);
+            }
+        }
+
+        self.placed.push((id, rect));
+        Some(rect)
+    }
+}
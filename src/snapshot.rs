@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+
+use crate::{layout::Placement, window::WindowGeometry};
+
+/// Position and scale of the windows before entering an overview, used to put
+/// them back when leaving it.
+#[derive(Clone, Debug, Default)]
+pub struct Snapshot {
+    placements: HashMap<usize, Placement>,
+}
+
+impl Snapshot {
+    pub fn capture(windows: &[WindowGeometry]) -> Self {
+        let mut snapshot = Snapshot::default();
+        for window in windows {
+            snapshot.insert(window);
+        }
+        snapshot
+    }
+
+    /// Records the geometry of a window, e.g. one created while the overview
+    /// is shown.
+    pub fn insert(&mut self, window: &WindowGeometry) {
+        self.placements.insert(
+            window.id,
+            Placement::new(window.id, window.x, window.y, window.scale),
+        );
+    }
+
+    pub fn remove(&mut self, id: usize) -> Option<Placement> {
+        self.placements.remove(&id)
+    }
+
+    pub fn get(&self, id: usize) -> Option<Placement> {
+        self.placements.get(&id).copied()
+    }
+
+    /// Placements bringing `windows` back to the captured geometry.
+    ///
+    /// Windows that were not captured go back to their natural scale where
+    /// they are; captured windows that no longer exist are ignored.
+    pub fn restore(&self, windows: &[WindowGeometry]) -> Vec<Placement> {
+        windows
+            .iter()
+            .map(|window| {
+                self.get(window.id)
+                    .unwrap_or_else(|| Placement::new(window.id, window.x, window.y, 1.0))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,118 @@
+use std::{path::PathBuf, str::FromStr};
+
+use super::{SceneGenerator, STRATEGIES};
+
+/// Command line of the demo.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cli {
+    /// Render a single frame to `out` instead of opening a window.
+    pub headless: bool,
+    pub out: PathBuf,
+    /// Animation recorded instead of opening a window, a GIF or an animated
+    /// PNG depending on the extension.
+    pub record: Option<PathBuf>,
+    /// Directory the recorded frames are also written to, one PNG each.
+    pub frames_dir: Option<PathBuf>,
+    /// Overview shown by the headless modes, one of [`STRATEGIES`].
+    pub strategy: Option<String>,
+    /// Random windows shown, from `--seed` or a random seed.
+    pub scene: SceneGenerator,
+    /// Scene file loaded instead of random windows.
+    pub scene_file: Option<PathBuf>,
+    /// File the viewer writes the current scene to.
+    pub scene_out: PathBuf,
+    /// Workspace of the scene shown.
+    pub workspace: usize,
+    /// Time the headless modes run the animations for, in seconds.
+    pub duration: f32,
+    /// Frames per second of the recording, the timestep of the animations.
+    pub fps: u32,
+}
+
+impl Default for Cli {
+    fn default() -> Self {
+        Cli {
+            headless: false,
+            out: PathBuf::from("frame.png"),
+            record: None,
+            frames_dir: None,
+            strategy: None,
+            scene: SceneGenerator::default(),
+            scene_file: None,
+            scene_out: PathBuf::from("scene.toml"),
+            workspace: 0,
+            duration: 1.0,
+            fps: 30,
+        }
+    }
+}
+
+pub const USAGE: &str =
+    "usage: expose-layers [--headless] [--out FILE] [--record FILE.gif|FILE.png] \
+[--frames-dir DIR] [--strategy expose|bin_pack|bin_pack2|rows|natural] [--duration SECONDS] \
+[--fps N] [--seed N] [--windows N] [--width D] [--height D] [--x D] [--y D] \
+[--scene FILE.toml] [--scene-out FILE.toml] [--workspace N]
+where the distributions D are MIN..MAX or MEAN~STD_DEV";
+
+/// Value following `flag`.
+fn value<T: FromStr>(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<T, String> {
+    let value = args.next().ok_or(format!("{flag} needs a value"))?;
+    value
+        .parse()
+        .map_err(|_| format!("invalid value `{value}` for {flag}"))
+}
+
+impl Cli {
+    /// Parses the arguments, program name excluded.
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut cli = Cli::default();
+        let mut seed = None;
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--headless" => cli.headless = true,
+                "--out" => cli.out = value(&mut args, &arg)?,
+                "--record" => cli.record = Some(value(&mut args, &arg)?),
+                "--frames-dir" => cli.frames_dir = Some(value(&mut args, &arg)?),
+                "--strategy" => {
+                    let strategy: String = value(&mut args, &arg)?;
+                    if !STRATEGIES.contains(&strategy.as_str()) {
+                        return Err(format!("unknown strategy `{strategy}`\n{USAGE}"));
+                    }
+                    cli.strategy = Some(strategy);
+                }
+                "--seed" => seed = Some(value(&mut args, &arg)?),
+                "--windows" => cli.scene.count = value(&mut args, &arg)?,
+                "--width" => cli.scene.width = value(&mut args, &arg)?,
+                "--height" => cli.scene.height = value(&mut args, &arg)?,
+                "--x" => cli.scene.x = value(&mut args, &arg)?,
+                "--y" => cli.scene.y = value(&mut args, &arg)?,
+                "--scene" => cli.scene_file = Some(value(&mut args, &arg)?),
+                "--scene-out" => cli.scene_out = value(&mut args, &arg)?,
+                "--workspace" => cli.workspace = value(&mut args, &arg)?,
+                "--duration" => cli.duration = value(&mut args, &arg)?,
+                "--fps" => {
+                    cli.fps = value(&mut args, &arg)?;
+                    if cli.fps == 0 {
+                        return Err("--fps must be positive".to_string());
+                    }
+                }
+                "-h" | "--help" => return Err(USAGE.to_string()),
+                _ => return Err(format!("unknown argument `{arg}`\n{USAGE}")),
+            }
+        }
+        cli.scene.seed = seed.unwrap_or_else(rand::random);
+        Ok(cli)
+    }
+
+    /// Fixed timestep of the headless modes.
+    pub fn timestep(&self) -> f32 {
+        1.0 / self.fps as f32
+    }
+
+    /// Number of frames recorded, the first one before the overview is
+    /// shown.
+    pub fn frames(&self) -> u32 {
+        (self.duration.max(0.0) * self.fps as f32).round() as u32 + 1
+    }
+}
@@ -0,0 +1,132 @@
+use std::{fmt, ops::Range, str::FromStr};
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use super::{Rgb, Scene, WindowDescription};
+
+/// How a window dimension or coordinate is drawn.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Distribution {
+    /// Any value in `min..max` equally likely, written `MIN..MAX`.
+    Uniform { min: f32, max: f32 },
+    /// Bell curve around `mean`, written `MEAN~STD_DEV`.
+    Normal { mean: f32, std_dev: f32 },
+}
+
+impl Distribution {
+    pub fn uniform(range: Range<f32>) -> Self {
+        Distribution::Uniform {
+            min: range.start,
+            max: range.end,
+        }
+    }
+
+    pub fn sample(&self, rng: &mut impl Rng) -> f32 {
+        match *self {
+            Distribution::Uniform { min, max } if min < max => rng.gen_range(min..max),
+            Distribution::Uniform { min, .. } => min,
+            Distribution::Normal { mean, std_dev } => {
+                // Box-Muller transform
+                let u: f32 = 1.0 - rng.gen::<f32>();
+                let v: f32 = rng.gen();
+                mean + std_dev * (-2.0 * u.ln()).sqrt() * (std::f32::consts::TAU * v).cos()
+            }
+        }
+    }
+}
+
+impl FromStr for Distribution {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let number = |value: &str| {
+            value
+                .trim()
+                .parse::<f32>()
+                .map_err(|_| format!("invalid number `{value}` in `{s}`"))
+        };
+        if let Some((min, max)) = s.split_once("..") {
+            Ok(Distribution::Uniform {
+                min: number(min)?,
+                max: number(max)?,
+            })
+        } else if let Some((mean, std_dev)) = s.split_once('~') {
+            Ok(Distribution::Normal {
+                mean: number(mean)?,
+                std_dev: number(std_dev)?,
+            })
+        } else {
+            Err(format!("`{s}` is neither MIN..MAX nor MEAN~STD_DEV"))
+        }
+    }
+}
+
+impl fmt::Display for Distribution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Distribution::Uniform { min, max } => write!(f, "{min}..{max}"),
+            Distribution::Normal { mean, std_dev } => write!(f, "{mean}~{std_dev}"),
+        }
+    }
+}
+
+/// Smallest window side, whatever the distribution draws.
+const MIN_WINDOW_SIZE: f32 = 50.0;
+
+/// Random windows of the demo, the same ones for a given seed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SceneGenerator {
+    pub seed: u64,
+    pub count: usize,
+    pub width: Distribution,
+    pub height: Distribution,
+    pub x: Distribution,
+    pub y: Distribution,
+}
+
+impl Default for SceneGenerator {
+    fn default() -> Self {
+        SceneGenerator {
+            seed: 0,
+            count: 10,
+            width: Distribution::uniform(200.0..1000.0),
+            height: Distribution::uniform(300.0..1000.0),
+            x: Distribution::uniform(0.0..2000.0),
+            y: Distribution::uniform(0.0..2000.0),
+        }
+    }
+}
+
+impl SceneGenerator {
+    /// Random number generator all the windows are drawn from, windows
+    /// added later included.
+    pub fn rng(&self) -> StdRng {
+        StdRng::seed_from_u64(self.seed)
+    }
+
+    /// Scene of `count` random windows.
+    pub fn scene(&self, rng: &mut StdRng) -> Scene {
+        Scene {
+            windows: (0..self.count)
+                .map(|id| self.description(id, rng))
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Window `id` of random size, color and position.
+    pub fn description(&self, id: usize, rng: &mut StdRng) -> WindowDescription {
+        let width = self.width.sample(rng).max(MIN_WINDOW_SIZE);
+        let height = self.height.sample(rng).max(MIN_WINDOW_SIZE);
+        let color = Rgb(
+            rng.gen_range(0..255),
+            rng.gen_range(0..255),
+            rng.gen_range(0..255),
+        );
+        WindowDescription {
+            x: self.x.sample(rng),
+            y: self.y.sample(rng),
+            ..WindowDescription::new(id, width, height, color)
+        }
+    }
+}
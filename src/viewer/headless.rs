@@ -0,0 +1,49 @@
+use layers::{prelude::*, skia};
+
+/// Draws the scene on the CPU, for machines without a GPU and for comparing
+/// frames in tests.
+pub struct HeadlessRenderer {
+    surface: skia::Surface,
+}
+
+impl HeadlessRenderer {
+    pub fn new(width: i32, height: i32) -> Self {
+        let surface = skia::Surface::new_raster_n32_premul((width, height))
+            .expect("raster surface of a positive size");
+        HeadlessRenderer { surface }
+    }
+
+    /// Draws the scene of `engine` over a cleared frame. The engine must have
+    /// been updated for the layout of the layers to be computed.
+    pub fn draw_scene(&mut self, engine: &LayersEngine) {
+        let canvas = self.surface.canvas();
+        canvas.clear(skia::Color::WHITE);
+        if let Some(root) = engine.scene_root() {
+            layers::drawing::scene::draw_scene(canvas, engine.scene(), root);
+        }
+    }
+
+    /// Canvas of the frame, to draw over the scene.
+    pub fn canvas(&mut self) -> &mut skia::Canvas {
+        self.surface.canvas()
+    }
+
+    /// Pixels of the frame, not premultiplied.
+    pub fn image(&mut self) -> image::RgbaImage {
+        let (width, height) = (self.surface.width(), self.surface.height());
+        let info = skia::ImageInfo::new(
+            (width, height),
+            skia::ColorType::RGBA8888,
+            skia::AlphaType::Unpremul,
+            None,
+        );
+        let row_bytes = width as usize * 4;
+        let mut pixels = vec![0; row_bytes * height as usize];
+        let read = self
+            .surface
+            .read_pixels(&info, &mut pixels, row_bytes, (0, 0));
+        assert!(read, "reading back a raster surface");
+        image::RgbaImage::from_raw(width as u32, height as u32, pixels)
+            .expect("buffer sized for the frame")
+    }
+}
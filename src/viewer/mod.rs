@@ -0,0 +1,112 @@
+//! Glue between the layout library and the layers of the demo scene.
+
+use expose_layers::{
+    layout::{
+        BinPack, BinPack2, Expose, JustifiedRows, LayoutArea, LayoutOptions, LayoutStrategy,
+        Natural, Placement,
+    },
+    window::{window_geometry, WindowGeometry},
+};
+use layers::{prelude::*, skia};
+
+mod cli;
+mod generator;
+mod headless;
+mod outputs;
+mod overview;
+mod pager;
+mod recorder;
+mod reserved;
+mod scene;
+mod scroller;
+
+pub use cli::Cli;
+pub use generator::SceneGenerator;
+pub use headless::HeadlessRenderer;
+pub use outputs::SimulatedOutputs;
+pub use overview::Overview;
+pub use pager::Pager;
+pub use recorder::Recorder;
+pub use reserved::demo_area;
+pub use scene::{Desktop, Rgb, Scene, WindowDescription};
+pub use scroller::Scroller;
+
+/// Strategies selectable from the command line.
+pub const STRATEGIES: [&str; 5] = ["expose", "bin_pack", "bin_pack2", "rows", "natural"];
+
+/// Strategy called `name` in [`STRATEGIES`].
+pub fn strategy_by_name(name: &str) -> Option<Box<dyn LayoutStrategy>> {
+    let strategy: Box<dyn LayoutStrategy> = match name {
+        "expose" => Box::new(Expose::default()),
+        "bin_pack" => Box::new(BinPack),
+        "bin_pack2" => Box::new(BinPack2 {
+            balance: true,
+            ..Default::default()
+        }),
+        "rows" => Box::new(JustifiedRows),
+        "natural" => Box::new(Natural::default()),
+        _ => return None,
+    };
+    Some(strategy)
+}
+
+/// Draws what the demo simulates over the scene: reserved space, outputs
+/// and the page indicator.
+pub fn draw_overlay(
+    canvas: &mut skia::Canvas,
+    area: &LayoutArea,
+    outputs: bool,
+    overview: &Overview,
+) {
+    reserved::draw_reserved(canvas, area);
+    if outputs {
+        outputs::draw_outputs(canvas, area);
+    }
+    overview
+        .pager()
+        .draw_indicator(canvas, area.rect.width, area.rect.height);
+}
+
+pub fn apply_layout(
+    windows: &[Layer],
+    strategy: &dyn LayoutStrategy,
+    area: &LayoutArea,
+    options: &LayoutOptions,
+) {
+    let geometries: Vec<WindowGeometry> = windows.iter().map(window_geometry).collect();
+    let placements = strategy.layout(&geometries, area, options);
+    apply_placements(windows, &placements, Some(Transition::default()));
+}
+
+pub fn apply_placements(
+    windows: &[Layer],
+    placements: &[Placement],
+    transition: Option<Transition>,
+) {
+    for window in windows {
+        let id: usize = window.id().unwrap().0.into();
+        if let Some(placement) = placements.iter().find(|placement| placement.id == id) {
+            window.set_scale((placement.scale, placement.scale), transition);
+            window.set_position((placement.x, placement.y), transition);
+        }
+    }
+}
+
+/// `BinPack2` printing how each packing went.
+pub struct ReportingBinPack2(pub BinPack2);
+
+impl LayoutStrategy for ReportingBinPack2 {
+    fn layout(
+        &self,
+        windows: &[WindowGeometry],
+        area: &LayoutArea,
+        options: &LayoutOptions,
+    ) -> Vec<Placement> {
+        let result = self.0.pack(windows, area, options);
+        println!(
+            "bin_pack2: {:?} scale {:.3} after {} attempts",
+            result.packer, result.scale, result.attempts
+        );
+        result.placements
+    }
+}
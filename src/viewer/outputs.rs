@@ -0,0 +1,86 @@
+use expose_layers::{
+    layout::{
+        LayoutArea, LayoutOptions, LayoutStrategy, MultiOutput, Output, OutputAssignment,
+        Placement, Rect,
+    },
+    window::WindowGeometry,
+};
+use layers::skia;
+
+/// Splits the demo window in a large monitor and a smaller, HiDPI one on its
+/// right, to try multi-monitor layouts with a single window. Both get the
+/// insets and reserved rectangles of `area`.
+pub fn simulated_outputs(area: &LayoutArea) -> Vec<Output> {
+    let output = area.rect;
+    let left_width = (output.width * 0.6).floor();
+    let right_height = (output.height * 0.7).floor();
+    let left = Rect::new(output.x, output.y, left_width, output.height);
+    let right = Rect::new(
+        output.x + left_width,
+        output.y + (output.height - right_height) * 0.5,
+        output.width - left_width,
+        right_height,
+    );
+    [(left, 1.0), (right, 2.0)]
+        .into_iter()
+        .map(|(rect, scale_factor)| Output {
+            area: LayoutArea {
+                rect,
+                ..area.clone()
+            },
+            scale_factor,
+        })
+        .collect()
+}
+
+/// Runs a strategy on the [`simulated_outputs`] of the output it is given.
+pub struct SimulatedOutputs {
+    pub strategy: Box<dyn LayoutStrategy>,
+    pub assignment: OutputAssignment,
+}
+
+impl SimulatedOutputs {
+    /// Runs `strategy` on the simulated outputs with `assignment`, or on the
+    /// whole output without one.
+    pub fn wrap(
+        strategy: Box<dyn LayoutStrategy>,
+        assignment: Option<OutputAssignment>,
+    ) -> Box<dyn LayoutStrategy> {
+        match assignment {
+            Some(assignment) => Box::new(SimulatedOutputs {
+                strategy,
+                assignment,
+            }),
+            None => strategy,
+        }
+    }
+}
+
+impl LayoutStrategy for SimulatedOutputs {
+    fn layout(
+        &self,
+        windows: &[WindowGeometry],
+        area: &LayoutArea,
+        options: &LayoutOptions,
+    ) -> Vec<Placement> {
+        let multi_output = MultiOutput {
+            strategy: self.strategy.as_ref(),
+            assignment: self.assignment,
+        };
+        multi_output.layout_outputs(windows, &simulated_outputs(area), options)
+    }
+}
+
+/// Outlines the simulated outputs.
+pub fn draw_outputs(canvas: &mut skia::Canvas, area: &LayoutArea) {
+    let mut paint = skia::Paint::new(skia::Color4f::new(0.2, 0.2, 0.8, 0.8), None);
+    paint.set_stroke(true);
+    paint.set_stroke_width(4.0);
+    for output in simulated_outputs(area) {
+        let rect = output.area.rect;
+        canvas.draw_rect(
+            skia::Rect::from_xywh(rect.x, rect.y, rect.width, rect.height),
+            &paint,
+        );
+    }
+}
@@ -0,0 +1,345 @@
+use expose_layers::{
+    gesture::Gesture,
+    layout::{
+        page_count, Incremental, LayoutArea, LayoutOptions, LayoutStrategy, Placement, Scrollable,
+        ScrollableLayout,
+    },
+    snapshot::Snapshot,
+    window::{window_geometry, window_size, WindowGeometry},
+};
+use layers::prelude::*;
+
+use super::{apply_placements, Pager, Scroller};
+
+/// Gesture in progress, towards the layout of `strategy`.
+struct Scrub {
+    gesture: Gesture,
+    strategy: Box<dyn LayoutStrategy>,
+    /// Height of the content the gesture ends in.
+    content_height: f32,
+    /// Whether the gesture entered the overview, and so leaves it when
+    /// cancelled.
+    entered: bool,
+}
+
+/// State of the overview shown over the demo windows.
+pub struct Overview {
+    /// Layer containing the windows, moved to switch page and to scroll.
+    root: Layer,
+    options: LayoutOptions,
+    /// Thumbnails smaller than this make the overview scrollable instead.
+    min_thumbnail_size: f32,
+    /// Strategy of the layout shown, re-run when the output changes.
+    strategy: Option<Box<dyn LayoutStrategy>>,
+    /// Thumbnails shown, updated as windows are inserted and removed.
+    placements: Vec<Placement>,
+    /// Area the thumbnails were laid out in, taller than the output when the
+    /// overview scrolls.
+    content: LayoutArea,
+    /// Geometry of the windows before the overview was entered.
+    snapshot: Option<Snapshot>,
+    scrub: Option<Scrub>,
+    pager: Pager,
+    scroller: Scroller,
+    /// Layers of the windows closed, with the time left until they are
+    /// animated out and removed from the engine.
+    closing: Vec<(Layer, f32)>,
+}
+
+impl Overview {
+    pub fn new(root: Layer, options: LayoutOptions, min_thumbnail_size: f32) -> Self {
+        Overview {
+            root,
+            options,
+            min_thumbnail_size,
+            strategy: None,
+            placements: Vec::new(),
+            content: LayoutArea::default(),
+            snapshot: None,
+            scrub: None,
+            pager: Pager::default(),
+            scroller: Scroller::default(),
+            closing: Vec::new(),
+        }
+    }
+
+    pub fn pager(&self) -> &Pager {
+        &self.pager
+    }
+
+    fn geometries(windows: &[Layer]) -> Vec<WindowGeometry> {
+        windows.iter().map(window_geometry).collect()
+    }
+
+    /// Geometry of `windows` outside of the overview, the one they are put
+    /// back to when leaving it.
+    pub fn desktop_geometry(&self, windows: &[Layer]) -> Vec<WindowGeometry> {
+        let geometries = Self::geometries(windows);
+        let Some(snapshot) = &self.snapshot else {
+            return geometries;
+        };
+        let placements = snapshot.restore(&geometries);
+        geometries
+            .into_iter()
+            .map(
+                |window| match placements.iter().find(|p| p.id == window.id) {
+                    Some(placement) => WindowGeometry {
+                        x: placement.x,
+                        y: placement.y,
+                        scale: placement.scale,
+                        ..window
+                    },
+                    None => window,
+                },
+            )
+            .collect()
+    }
+
+    /// Captures the windows geometry, unless already in the overview.
+    fn enter(&mut self, geometries: &[WindowGeometry]) {
+        if self.snapshot.is_none() {
+            self.snapshot = Some(Snapshot::capture(geometries));
+        }
+    }
+
+    /// Moves the root layer to show the current page at the scroll offset.
+    fn pan(&self, area: &LayoutArea, transition: Option<Transition>) {
+        let x = -(self.pager.page() as f32) * area.rect.width;
+        let y = -self.scroller.offset();
+        self.root.set_position((x, y), transition);
+    }
+
+    /// Animates the windows to the layout computed by `strategy`.
+    pub fn show(
+        &mut self,
+        windows: &[Layer],
+        strategy: Box<dyn LayoutStrategy>,
+        area: &LayoutArea,
+    ) {
+        self.enter(&Self::geometries(windows));
+        self.scrub = None;
+        self.strategy = Some(strategy);
+        self.relayout(windows, area);
+    }
+
+    /// Runs the layout shown again, e.g. after the output is resized or its
+    /// reserved space changes. The page and the scroll offset are kept when
+    /// still in range.
+    pub fn relayout(&mut self, windows: &[Layer], area: &LayoutArea) {
+        let Some(strategy) = self.strategy.as_deref() else {
+            return;
+        };
+        let layout = self.layout(strategy, windows, area);
+        apply_placements(windows, &layout.placements, Some(Transition::default()));
+        self.shown(layout.placements, layout.content_height, area);
+    }
+
+    /// Layout of `strategy`, from where the windows are on the desktop, not
+    /// from their current thumbnails.
+    fn layout(
+        &self,
+        strategy: &dyn LayoutStrategy,
+        windows: &[Layer],
+        area: &LayoutArea,
+    ) -> ScrollableLayout {
+        let scrollable = Scrollable {
+            strategy,
+            min_thumbnail_size: self.min_thumbnail_size,
+        };
+        scrollable.layout_scrollable(&self.desktop_geometry(windows), area, &self.options)
+    }
+
+    /// Keeps `placements` as the thumbnails shown, and sets the pages and
+    /// the scrolling range for them.
+    fn shown(&mut self, placements: Vec<Placement>, content_height: f32, area: &LayoutArea) {
+        self.pager.set_pages(page_count(&placements));
+        self.scroller.set_content(content_height, area.rect.height);
+        self.pan(area, Some(Transition::default()));
+        self.placements = placements;
+        self.content = area.with_height(content_height);
+    }
+
+    /// Adds `window`, already in `windows`, to the overview. It fades and
+    /// grows in where there is room, the other thumbnails only move when
+    /// there is none.
+    pub fn insert_window(&mut self, windows: &[Layer], window: &Layer) {
+        let geometry = window_geometry(window);
+        if let Some(snapshot) = self.snapshot.as_mut() {
+            snapshot.insert(&geometry);
+        }
+        let Some(strategy) = self.strategy.as_deref() else {
+            return;
+        };
+        let shown: Vec<WindowGeometry> = Self::geometries(windows)
+            .into_iter()
+            .filter(|shown| shown.id != geometry.id)
+            .collect();
+        let incremental = Incremental { strategy };
+        self.placements = incremental.insert(
+            &shown,
+            &self.placements,
+            &geometry,
+            &self.content,
+            &self.options,
+        );
+
+        // start as a point in the middle of its slot
+        if let Some(placement) = self.placements.iter().find(|p| p.id == geometry.id) {
+            let (width, height) = window_size(window);
+            window.set_opacity(0.0, None);
+            window.set_scale((0.0, 0.0), None);
+            window.set_position(
+                (
+                    placement.x + width * placement.scale * 0.5,
+                    placement.y + height * placement.scale * 0.5,
+                ),
+                None,
+            );
+            window.set_opacity(1.0, Some(Transition::default()));
+        }
+        apply_placements(windows, &self.placements, Some(Transition::default()));
+        self.pager.set_pages(page_count(&self.placements));
+    }
+
+    /// Animates `window` out, shrinking and fading to the middle of its
+    /// thumbnail, then removed from the engine by [`Overview::tick`]. It is
+    /// expected to be gone from `windows`, the remaining thumbnails stay
+    /// where they are.
+    pub fn remove_window(&mut self, window: &Layer) {
+        let geometry = window_geometry(window);
+        let (width, height) = window_size(window);
+        let transition = Transition::default();
+        self.closing
+            .push((window.clone(), transition.delay + transition.duration));
+        let transition = Some(transition);
+        window.set_position(
+            (
+                geometry.x + width * geometry.scale * 0.5,
+                geometry.y + height * geometry.scale * 0.5,
+            ),
+            transition,
+        );
+        window.set_scale((0.0, 0.0), transition);
+        window.set_opacity(0.0, transition);
+
+        if let Some(snapshot) = self.snapshot.as_mut() {
+            snapshot.remove(geometry.id);
+        }
+        if let Some(strategy) = self.strategy.as_deref() {
+            self.placements = Incremental { strategy }.remove(&self.placements, geometry.id);
+            self.pager.set_pages(page_count(&self.placements));
+        }
+    }
+
+    pub fn next_page(&mut self, area: &LayoutArea) {
+        self.pager.next();
+        self.pan(area, Some(Transition::default()));
+    }
+
+    pub fn previous_page(&mut self, area: &LayoutArea) {
+        self.pager.previous();
+        self.pan(area, Some(Transition::default()));
+    }
+
+    /// Switches page with horizontal scrolling.
+    pub fn scroll_pages(&mut self, delta: f32, area: &LayoutArea) {
+        if self.pager.scroll(delta) {
+            self.pan(area, Some(Transition::default()));
+        }
+    }
+
+    /// Whether the overview is taller than the output.
+    pub fn scrollable(&self) -> bool {
+        self.scroller.can_scroll()
+    }
+
+    pub fn scroll(&mut self, delta: f32, momentum: bool, area: &LayoutArea) {
+        self.scroller.scroll(delta, momentum);
+        self.pan(area, None);
+    }
+
+    /// Advances the scrolling momentum and removes the closed windows done
+    /// animating out, to be called every frame.
+    pub fn tick(&mut self, engine: &LayersEngine, dt: f32, area: &LayoutArea) {
+        if self.scroller.tick(dt) {
+            self.pan(area, None);
+        }
+        self.closing.retain_mut(|(window, remaining)| {
+            *remaining -= dt;
+            if *remaining > 0.0 {
+                return true;
+            }
+            engine.scene_remove_layer(window.id());
+            false
+        });
+    }
+
+    /// Moves the gesture towards `strategy` by `delta`, starting it from the
+    /// current window geometry if none is in progress. `strategy` is ignored
+    /// while a gesture is in progress.
+    pub fn scrub(
+        &mut self,
+        windows: &[Layer],
+        strategy: Box<dyn LayoutStrategy>,
+        area: &LayoutArea,
+        delta: f32,
+    ) {
+        if self.scrub.is_none() {
+            let geometries = Self::geometries(windows);
+            let entered = self.snapshot.is_none();
+            self.enter(&geometries);
+            let target = self.layout(strategy.as_ref(), windows, area);
+            self.scrub = Some(Scrub {
+                gesture: Gesture::begin(&geometries, &target.placements),
+                strategy,
+                content_height: target.content_height,
+                entered,
+            });
+        }
+        let gesture = &mut self.scrub.as_mut().unwrap().gesture;
+        let progress = gesture.progress() + delta;
+        apply_placements(windows, &gesture.update(progress), None);
+    }
+
+    /// Animates the gesture in progress to its end, which is then the layout
+    /// shown, as with [`Overview::show`].
+    pub fn commit(&mut self, windows: &[Layer], area: &LayoutArea) {
+        if let Some(scrub) = self.scrub.take() {
+            let placements = scrub.gesture.commit();
+            apply_placements(windows, &placements, Some(Transition::default()));
+            self.strategy = Some(scrub.strategy);
+            self.shown(placements, scrub.content_height, area);
+        }
+    }
+
+    /// Animates the gesture in progress back to its start. The overview is
+    /// left only if the gesture entered it, a gesture started from a layout
+    /// shown goes back to it.
+    pub fn cancel(&mut self, windows: &[Layer]) {
+        if let Some(scrub) = self.scrub.take() {
+            apply_placements(
+                windows,
+                &scrub.gesture.cancel(),
+                Some(Transition::default()),
+            );
+            if scrub.entered {
+                self.snapshot = None;
+            }
+        }
+    }
+
+    /// Leaves the overview, animating every window back to the geometry it had
+    /// before entering it.
+    pub fn restore(&mut self, windows: &[Layer], area: &LayoutArea) {
+        self.scrub = None;
+        self.strategy = None;
+        self.placements.clear();
+        self.pager.set_pages(1);
+        self.scroller.reset();
+        self.pan(area, Some(Transition::default()));
+        if let Some(snapshot) = self.snapshot.take() {
+            let placements = snapshot.restore(&Self::geometries(windows));
+            apply_placements(windows, &placements, Some(Transition::default()));
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use layers::skia;
+
+/// Pixels of horizontal scrolling needed to change page.
+const SCROLL_THRESHOLD: f32 = 100.0;
+
+/// Pages of the overview, laid out side by side.
+#[derive(Debug)]
+pub struct Pager {
+    page: usize,
+    pages: usize,
+    /// Horizontal scrolling accumulated since the last page change.
+    scroll: f32,
+}
+
+impl Default for Pager {
+    fn default() -> Self {
+        Pager {
+            page: 0,
+            pages: 1,
+            scroll: 0.0,
+        }
+    }
+}
+
+impl Pager {
+    pub fn page(&self) -> usize {
+        self.page
+    }
+
+    pub fn pages(&self) -> usize {
+        self.pages
+    }
+
+    /// Updates the number of pages, keeping the current one if it still
+    /// exists.
+    pub fn set_pages(&mut self, pages: usize) {
+        self.pages = pages.max(1);
+        self.go_to(self.page);
+    }
+
+    pub fn go_to(&mut self, page: usize) {
+        self.page = page.min(self.pages - 1);
+        self.scroll = 0.0;
+    }
+
+    pub fn next(&mut self) {
+        self.go_to(self.page + 1);
+    }
+
+    pub fn previous(&mut self) {
+        self.go_to(self.page.saturating_sub(1));
+    }
+
+    /// Accumulates horizontal scrolling, returning `true` once it is past the
+    /// threshold and the page changed.
+    pub fn scroll(&mut self, delta: f32) -> bool {
+        self.scroll += delta;
+        if self.scroll > SCROLL_THRESHOLD {
+            self.previous();
+            true
+        } else if self.scroll < -SCROLL_THRESHOLD {
+            self.next();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Draws a dot per page at the bottom of the screen, the current one
+    /// filled.
+    pub fn draw_indicator(&self, canvas: &mut skia::Canvas, width: f32, height: f32) {
+        if self.pages < 2 {
+            return;
+        }
+        let radius = 8.0;
+        let spacing = 32.0;
+        let start_x = (width - spacing * (self.pages - 1) as f32) * 0.5;
+        let y = height - 40.0;
+
+        let mut paint = skia::Paint::new(skia::Color4f::new(0.0, 0.0, 0.0, 0.6), None);
+        paint.set_anti_alias(true);
+        paint.set_stroke_width(2.0);
+        for page in 0..self.pages {
+            paint.set_stroke(page != self.page);
+            canvas.draw_circle((start_x + spacing * page as f32, y), radius, &paint);
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use std::{
+    error::Error,
+    fs::{self, File},
+    io::BufWriter,
+    path::{Path, PathBuf},
+};
+
+use image::{
+    codecs::gif::{GifEncoder, Repeat},
+    Delay, Frame, RgbaImage,
+};
+
+/// Encoder of the animation, chosen by the file extension.
+enum Animation {
+    Gif(GifEncoder<BufWriter<File>>),
+    Png(png::Writer<BufWriter<File>>),
+}
+
+/// Writes frames rendered at a fixed rate as they come, to an animated GIF
+/// or PNG and optionally to a directory of numbered PNGs.
+pub struct Recorder {
+    animation: Option<Animation>,
+    frames_dir: Option<PathBuf>,
+    fps: u32,
+    frame: u32,
+}
+
+impl Recorder {
+    /// Starts recording `frames` frames of `width x height` at `fps`. The
+    /// animated PNG needs its frame count upfront, so exactly `frames` must
+    /// be pushed.
+    pub fn create(
+        out: Option<&Path>,
+        frames_dir: Option<&Path>,
+        (width, height): (u32, u32),
+        frames: u32,
+        fps: u32,
+    ) -> Result<Self, Box<dyn Error>> {
+        let animation = match out {
+            Some(out) => {
+                let extension = out.extension().and_then(|extension| extension.to_str());
+                let gif = match extension.map(str::to_ascii_lowercase).as_deref() {
+                    Some("gif") => true,
+                    Some("png" | "apng") => false,
+                    _ => return Err(format!("{} is neither a GIF nor a PNG", out.display()).into()),
+                };
+                let file = BufWriter::new(File::create(out)?);
+                Some(if gif {
+                    let mut encoder = GifEncoder::new(file);
+                    encoder.set_repeat(Repeat::Infinite)?;
+                    Animation::Gif(encoder)
+                } else {
+                    let mut encoder = png::Encoder::new(file, width, height);
+                    encoder.set_color(png::ColorType::Rgba);
+                    encoder.set_depth(png::BitDepth::Eight);
+                    encoder.set_animated(frames, 0)?;
+                    encoder.set_frame_delay(1, fps.try_into()?)?;
+                    Animation::Png(encoder.write_header()?)
+                })
+            }
+            None => None,
+        };
+        if let Some(frames_dir) = frames_dir {
+            fs::create_dir_all(frames_dir)?;
+        }
+        Ok(Recorder {
+            animation,
+            frames_dir: frames_dir.map(Path::to_path_buf),
+            fps,
+            frame: 0,
+        })
+    }
+
+    pub fn push(&mut self, image: RgbaImage) -> Result<(), Box<dyn Error>> {
+        if let Some(frames_dir) = &self.frames_dir {
+            image.save(frames_dir.join(format!("frame_{:04}.png", self.frame)))?;
+        }
+        match &mut self.animation {
+            Some(Animation::Gif(encoder)) => {
+                let delay = Delay::from_numer_denom_ms(1000, self.fps);
+                encoder.encode_frame(Frame::from_parts(image, 0, 0, delay))?;
+            }
+            Some(Animation::Png(writer)) => writer.write_image_data(image.as_raw())?,
+            None => {}
+        }
+        self.frame += 1;
+        Ok(())
+    }
+
+    /// Completes the animation, the GIF being done once dropped.
+    pub fn finish(self) -> Result<(), Box<dyn Error>> {
+        if let Some(Animation::Png(writer)) = self.animation {
+            writer.finish()?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+use expose_layers::layout::{Insets, LayoutArea, Rect};
+use layers::skia;
+
+/// Layout area of the demo window, optionally with a simulated top panel,
+/// notch and dock for the layouts to avoid.
+pub fn demo_area(output: Rect, reserved: bool) -> LayoutArea {
+    if !reserved {
+        return LayoutArea::new(output);
+    }
+    let dock_width = (output.width * 0.5).min(800.0);
+    let notch_width = 200.0;
+    LayoutArea {
+        rect: output,
+        insets: Insets {
+            top: 32.0,
+            ..Default::default()
+        },
+        reserved: vec![
+            Rect::new(
+                output.x + (output.width - notch_width) * 0.5,
+                output.y,
+                notch_width,
+                80.0,
+            ),
+            Rect::new(
+                output.x + (output.width - dock_width) * 0.5,
+                output.y + output.height - 100.0,
+                dock_width,
+                100.0,
+            ),
+        ],
+    }
+}
+
+/// Fills the insets and reserved rectangles of `area`.
+pub fn draw_reserved(canvas: &mut skia::Canvas, area: &LayoutArea) {
+    let paint = skia::Paint::new(skia::Color4f::new(0.1, 0.1, 0.1, 0.5), None);
+    let rect = area.rect;
+    let insets = area.insets;
+    let edges = [
+        Rect::new(rect.x, rect.y, rect.width, insets.top),
+        Rect::new(
+            rect.x,
+            rect.y + rect.height - insets.bottom,
+            rect.width,
+            insets.bottom,
+        ),
+        Rect::new(rect.x, rect.y, insets.left, rect.height),
+        Rect::new(
+            rect.x + rect.width - insets.right,
+            rect.y,
+            insets.right,
+            rect.height,
+        ),
+    ];
+    for reserved in edges.iter().chain(&area.reserved) {
+        if reserved.width > 0.0 && reserved.height > 0.0 {
+            canvas.draw_rect(
+                skia::Rect::from_xywh(reserved.x, reserved.y, reserved.width, reserved.height),
+                &paint,
+            );
+        }
+    }
+}
@@ -0,0 +1,379 @@
+use std::{
+    error::Error,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use expose_layers::window::{window_geometry, WindowGeometry};
+use layers::{prelude::*, skia};
+use serde::{Deserialize, Serialize};
+
+/// Opaque color, written `#rrggbb`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Default for Rgb {
+    fn default() -> Self {
+        Rgb(255, 255, 255)
+    }
+}
+
+impl TryFrom<String> for Rgb {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let invalid = || format!("`{value}` is not a #rrggbb color");
+        let hex = value.strip_prefix('#').ok_or_else(invalid)?;
+        if hex.len() != 6 || !hex.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return Err(invalid());
+        }
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| invalid());
+        Ok(Rgb(channel(0)?, channel(2)?, channel(4)?))
+    }
+}
+
+impl fmt::Display for Rgb {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+impl From<Rgb> for String {
+    fn from(color: Rgb) -> Self {
+        color.to_string()
+    }
+}
+
+/// A window of the demo desktop.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WindowDescription {
+    pub id: usize,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub app_id: String,
+    pub width: f32,
+    pub height: f32,
+    #[serde(default)]
+    pub x: f32,
+    #[serde(default)]
+    pub y: f32,
+    #[serde(default)]
+    pub color: Rgb,
+    /// Stacking order, windows with a higher `z` are drawn over the others.
+    #[serde(default)]
+    pub z: i32,
+    #[serde(default)]
+    pub workspace: usize,
+    /// Picture drawn in the window, instead of the plain color.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image: Option<PathBuf>,
+}
+
+impl WindowDescription {
+    pub fn new(id: usize, width: f32, height: f32, color: Rgb) -> Self {
+        WindowDescription {
+            id,
+            title: format!("Window {id}"),
+            app_id: String::new(),
+            width,
+            height,
+            x: 0.0,
+            y: 0.0,
+            color,
+            z: 0,
+            workspace: 0,
+            image: None,
+        }
+    }
+}
+
+/// Windows of the demo desktop, as read from and written to a TOML file with
+/// a `[[window]]` table per window.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Scene {
+    #[serde(default, rename = "window")]
+    pub windows: Vec<WindowDescription>,
+    /// Directory relative image paths are taken from, the one of the file
+    /// the scene was loaded from.
+    #[serde(skip)]
+    pub dir: PathBuf,
+}
+
+impl Scene {
+    /// Reads the scene at `path`. Image paths are kept as written, relative
+    /// ones being taken from the directory of the file.
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let text = fs::read_to_string(path)?;
+        let mut scene: Scene =
+            toml::from_str(&text).map_err(|err| format!("{}: {err}", path.display()))?;
+        scene.dir = path.parent().unwrap_or(Path::new("")).to_path_buf();
+        Ok(scene)
+    }
+
+    /// Writes the scene to `path`. Relative image paths are kept when saving
+    /// next to the file the scene was read from, and made absolute otherwise
+    /// so they still point at the images.
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        let mut scene = self.clone();
+        let from = absolute_dir(&self.dir)?;
+        if from != absolute_dir(path.parent().unwrap_or(Path::new("")))? {
+            for window in &mut scene.windows {
+                if let Some(image) = &mut window.image {
+                    if image.is_relative() {
+                        *image = from.join(&*image);
+                    }
+                }
+            }
+        }
+        fs::write(path, toml::to_string_pretty(&scene)?)?;
+        Ok(())
+    }
+
+    /// Id not used by any window of the scene.
+    pub fn next_id(&self) -> usize {
+        self.windows
+            .iter()
+            .map(|window| window.id + 1)
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// `dir` made absolute, the current directory if empty.
+fn absolute_dir(dir: &Path) -> io::Result<PathBuf> {
+    if dir.as_os_str().is_empty() {
+        std::env::current_dir()
+    } else {
+        std::path::absolute(dir)
+    }
+}
+
+/// Decodes the picture at `path` for skia.
+fn load_image(path: &Path) -> Result<skia::Image, Box<dyn Error>> {
+    let picture = image::open(path)
+        .map_err(|err| format!("{}: {err}", path.display()))?
+        .to_rgba8();
+    let (width, height) = picture.dimensions();
+    let info = skia::ImageInfo::new(
+        (width as i32, height as i32),
+        skia::ColorType::RGBA8888,
+        skia::AlphaType::Unpremul,
+        None,
+    );
+    skia::Image::from_raster_data(
+        &info,
+        skia::Data::new_copy(picture.as_raw()),
+        width as usize * 4,
+    )
+    .ok_or_else(|| format!("{}: unsupported image", path.display()).into())
+}
+
+/// Adds the window described by `description` to the scene, with its image
+/// path taken from `dir`.
+fn new_window(
+    engine: &LayersEngine,
+    description: &WindowDescription,
+    dir: &Path,
+) -> Result<Layer, Box<dyn Error>> {
+    let window = engine.new_layer();
+    window.set_size(
+        layers::types::Size::points(description.width, description.height),
+        None,
+    );
+    let Rgb(r, g, b) = description.color;
+
+    window.set_background_color(
+        PaintColor::Solid {
+            color: Color::new_rgba255(r, g, b, 255),
+        },
+        None,
+    );
+    window.set_border_width(1.0, None);
+    window.set_border_color(
+        PaintColor::Solid {
+            color: Color::new_rgba255(0, 0, 0, 255),
+        },
+        None,
+    );
+    window.set_layout_style(taffy::Style {
+        position: taffy::Position::Absolute,
+        ..Default::default()
+    });
+    window.set_position((description.x, description.y), None);
+    window.set_border_corner_radius(BorderRadius::new_single(20.0), None);
+    if let Some(path) = &description.image {
+        let image = load_image(&dir.join(path))?;
+        window.set_draw_content(move |canvas: &mut skia::Canvas, width: f32, height: f32| {
+            let rect = skia::Rect::from_wh(width, height);
+            canvas.draw_image_rect(&image, None, rect, &skia::Paint::default());
+            rect
+        });
+    }
+    engine.scene_add_layer(window.clone());
+    Ok(window)
+}
+
+/// The windows of a scene shown on one of its workspaces.
+pub struct Desktop {
+    pub scene: Scene,
+    pub workspace: usize,
+    /// Layers of the windows on the workspace, from bottom to top.
+    pub windows: Vec<Layer>,
+    /// Scene id of each of `windows`.
+    ids: Vec<usize>,
+}
+
+impl Desktop {
+    /// Adds the windows of `scene` on `workspace` to the engine, in their
+    /// stacking order.
+    pub fn new(
+        engine: &LayersEngine,
+        scene: Scene,
+        workspace: usize,
+    ) -> Result<Self, Box<dyn Error>> {
+        let mut desktop = Desktop {
+            scene: Scene {
+                windows: Vec::new(),
+                dir: scene.dir,
+            },
+            workspace,
+            windows: Vec::new(),
+            ids: Vec::new(),
+        };
+        let mut descriptions = scene.windows;
+        descriptions.sort_by_key(|window| window.z);
+        for description in descriptions {
+            desktop.add(engine, description)?;
+        }
+        Ok(desktop)
+    }
+
+    /// Adds `description` to the scene, on top of the others. Its layer is
+    /// returned when on the workspace shown.
+    pub fn add(
+        &mut self,
+        engine: &LayersEngine,
+        description: WindowDescription,
+    ) -> Result<Option<Layer>, Box<dyn Error>> {
+        let mut layer = None;
+        if description.workspace == self.workspace {
+            let window = new_window(engine, &description, &self.scene.dir)?;
+            self.windows.push(window.clone());
+            self.ids.push(description.id);
+            layer = Some(window);
+        }
+        self.scene.windows.push(description);
+        Ok(layer)
+    }
+
+    /// Removes the topmost window of the workspace from the scene. Its layer
+    /// is left in the engine, for the caller to animate out and remove, e.g.
+    /// with [`super::Overview::remove_window`].
+    pub fn pop(&mut self) -> Option<Layer> {
+        let window = self.windows.pop()?;
+        let id = self.ids.pop()?;
+        self.scene
+            .windows
+            .retain(|description| description.id != id);
+        Some(window)
+    }
+
+    /// The scene with the windows of the workspace at `geometries`, in their
+    /// current stacking order.
+    pub fn capture(&self, geometries: &[WindowGeometry]) -> Scene {
+        let mut scene = self.scene.clone();
+        for (z, (window, id)) in self.windows.iter().zip(&self.ids).enumerate() {
+            let layer_id = window_geometry(window).id;
+            let geometry = geometries.iter().find(|geometry| geometry.id == layer_id);
+            let description = scene.windows.iter_mut().find(|window| window.id == *id);
+            if let (Some(geometry), Some(description)) = (geometry, description) {
+                description.x = geometry.x;
+                description.y = geometry.y;
+                description.width = geometry.width;
+                description.height = geometry.height;
+                description.z = z as i32;
+            }
+        }
+        scene
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn colors_are_written_rrggbb() {
+        let color = Rgb::try_from("#0a8BfF".to_string()).unwrap();
+        assert_eq!(color, Rgb(0x0a, 0x8b, 0xff));
+        assert_eq!(color.to_string(), "#0a8bff");
+        assert_eq!(Rgb::try_from(color.to_string()), Ok(color));
+        for invalid in [
+            "", "#", "0a8bff", "#0a8bf", "#0a8bff0", "#0a8bgg", "#0a8bé", "#+a8bff",
+        ] {
+            assert!(Rgb::try_from(invalid.to_string()).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn scenes_read_back_as_written() {
+        let mut image = WindowDescription::new(1, 640.0, 480.0, Rgb(1, 2, 3));
+        image.image = Some(PathBuf::from("images/photo.png"));
+        image.z = 2;
+        image.workspace = 1;
+        let scene = Scene {
+            windows: vec![
+                WindowDescription::new(0, 800.0, 600.0, Rgb(255, 128, 0)),
+                image,
+            ],
+            dir: PathBuf::new(),
+        };
+        let text = toml::to_string_pretty(&scene).unwrap();
+        let parsed: Scene = toml::from_str(&text).unwrap();
+        assert_eq!(parsed, scene);
+        assert_eq!(toml::to_string_pretty(&parsed).unwrap(), text);
+
+        let text = r##"
+[[window]]
+id = 3
+width = 300.5
+height = 200.0
+color = "#102030"
+image = "../shared/wallpaper.jpg"
+"##;
+        let parsed: Scene = toml::from_str(text).unwrap();
+        let written = toml::to_string_pretty(&parsed).unwrap();
+        assert_eq!(toml::from_str::<Scene>(&written).unwrap(), parsed);
+    }
+
+    #[test]
+    fn loading_keeps_the_image_paths() {
+        let dir = std::env::temp_dir().join(format!("expose-layers-scene-{}", std::process::id()));
+        let elsewhere = dir.join("elsewhere");
+        fs::create_dir_all(&elsewhere).unwrap();
+        let path = dir.join("scene.toml");
+        let mut window = WindowDescription::new(0, 800.0, 600.0, Rgb::default());
+        window.image = Some(PathBuf::from("photo.png"));
+        let mut absolute = WindowDescription::new(1, 800.0, 600.0, Rgb::default());
+        absolute.image = Some(dir.join("shared.png"));
+        let scene = Scene {
+            windows: vec![window, absolute],
+            dir: dir.clone(),
+        };
+        scene.save(&path).unwrap();
+
+        let loaded = Scene::load(&path).unwrap();
+        assert_eq!(loaded.dir, dir);
+        assert_eq!(loaded.windows, scene.windows);
+
+        // saved elsewhere, the relative path no longer would point at the image
+        let moved = elsewhere.join("scene.toml");
+        loaded.save(&moved).unwrap();
+        let moved = Scene::load(&moved).unwrap();
+        assert_eq!(moved.windows[0].image, Some(dir.join("photo.png")));
+        assert_eq!(moved.windows[1].image, scene.windows[1].image);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
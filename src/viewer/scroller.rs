@@ -0,0 +1,60 @@
+/// Velocity kept after each frame of momentum scrolling.
+const FRICTION: f32 = 0.95;
+/// Below this speed, in pixels per second, momentum stops.
+const MIN_VELOCITY: f32 = 10.0;
+const FRAME: f32 = 1.0 / 60.0;
+
+/// Vertical scrolling of an overview taller than the screen, with momentum.
+#[derive(Debug, Default)]
+pub struct Scroller {
+    offset: f32,
+    max_offset: f32,
+    /// Pixels per second, applied on the frames without scroll input.
+    velocity: f32,
+    input: bool,
+}
+
+impl Scroller {
+    pub fn offset(&self) -> f32 {
+        self.offset
+    }
+
+    pub fn can_scroll(&self) -> bool {
+        self.max_offset > 0.0
+    }
+
+    /// Updates the scrollable extent, keeping the current offset when still
+    /// in range.
+    pub fn set_content(&mut self, content_height: f32, viewport_height: f32) {
+        self.max_offset = (content_height - viewport_height).max(0.0);
+        self.offset = self.offset.clamp(0.0, self.max_offset);
+    }
+
+    pub fn reset(&mut self) {
+        *self = Scroller::default();
+    }
+
+    /// Scrolls by `delta` pixels, positive towards the top. With `momentum`
+    /// the scrolling continues, slowing down, once the input stops.
+    pub fn scroll(&mut self, delta: f32, momentum: bool) {
+        self.offset = (self.offset - delta).clamp(0.0, self.max_offset);
+        self.velocity = if momentum { -delta / FRAME } else { 0.0 };
+        self.input = true;
+    }
+
+    /// Advances the momentum by `dt` seconds, returning `true` if the offset
+    /// changed.
+    pub fn tick(&mut self, dt: f32) -> bool {
+        if std::mem::take(&mut self.input) || self.velocity.abs() < MIN_VELOCITY {
+            return false;
+        }
+        let offset = (self.offset + self.velocity * dt).clamp(0.0, self.max_offset);
+        if offset == 0.0 || offset == self.max_offset {
+            self.velocity = 0.0;
+        }
+        self.velocity *= FRICTION.powf(dt / FRAME);
+        let moved = offset != self.offset;
+        self.offset = offset;
+        moved
+    }
+}
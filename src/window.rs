@@ -0,0 +1,52 @@
+/// Geometry of a window as seen by the layout strategies, independent from the
+/// layer it is rendered with.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WindowGeometry {
+    pub id: usize,
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    pub scale: f32,
+}
+
+impl WindowGeometry {
+    pub fn area(&self) -> f32 {
+        self.width * self.height
+    }
+}
+
+/// Unscaled size of a window.
+///
+/// Sizes given in points are used as they are; any other dimension (`Auto`,
+/// `Percent`) is taken from the layout computed by the engine, so the size is
+/// only known after the first `engine.update`.
+#[cfg(feature = "layers")]
+pub fn window_size(window: &layers::prelude::Layer) -> (f32, f32) {
+    use layers::prelude::taffy;
+
+    let size = window.size();
+    match (size.width, size.height) {
+        (taffy::Dimension::Points(width), taffy::Dimension::Points(height)) => (width, height),
+        _ => {
+            let bounds = window.render_bounds();
+            (bounds.width(), bounds.height())
+        }
+    }
+}
+
+/// Geometry of the window rendered by `window`, identified by its layer id.
+#[cfg(feature = "layers")]
+pub fn window_geometry(window: &layers::prelude::Layer) -> WindowGeometry {
+    let (width, height) = window_size(window);
+    let position = window.position();
+    let id: usize = window.id().unwrap().0.into();
+    WindowGeometry {
+        id,
+        x: position.x,
+        y: position.y,
+        width,
+        height,
+        scale: window.scale().x,
+    }
+}
@@ -0,0 +1,207 @@
+mod common;
+
+use expose_layers::{
+    layout::{BinPack2, LayoutOptions, PackerKind, Rect},
+    window::WindowGeometry,
+};
+
+fn windows() -> Vec<WindowGeometry> {
+    common::windows(&[
+        (333.3, 901.7),
+        (917.5, 402.1),
+        (511.9, 498.4),
+        (1003.2, 297.6),
+        (207.7, 811.1),
+        (699.9, 613.3),
+        (401.4, 1000.6),
+        (812.8, 351.2),
+    ])
+}
+
+fn output() -> Rect {
+    Rect::new(13.5, 7.25, 1917.3, 1083.9)
+}
+
+/// Two squares in a square output, without margin or spacing: they fit side
+/// by side at half their size, not at the area ratio the search starts from.
+fn squares() -> (Vec<WindowGeometry>, LayoutOptions) {
+    let options = LayoutOptions {
+        margin: 0.0,
+        spacing: 0.0,
+        ..Default::default()
+    };
+    (
+        common::windows(&[(1000.0, 1000.0), (1000.0, 1000.0)]),
+        options,
+    )
+}
+
+#[test]
+fn repeated_packing_is_identical() {
+    let windows = windows();
+    for packer in PackerKind::ALL {
+        let bin_pack2 = BinPack2 {
+            packer,
+            ..Default::default()
+        };
+        let first = bin_pack2.pack(&windows, &output().into(), &LayoutOptions::default());
+        for _ in 0..10 {
+            let again = bin_pack2.pack(&windows, &output().into(), &LayoutOptions::default());
+            assert_eq!(first.scale.to_bits(), again.scale.to_bits(), "{packer:?}");
+            assert_eq!(first.placements, again.placements, "{packer:?}");
+        }
+    }
+}
+
+#[test]
+fn placements_match_the_packing_scale() {
+    let windows = windows();
+    for packer in PackerKind::ALL {
+        let bin_pack2 = BinPack2 {
+            packer,
+            ..Default::default()
+        };
+        let result = bin_pack2.pack(&windows, &output().into(), &LayoutOptions::default());
+        assert_eq!(result.placements.len(), windows.len(), "{packer:?}");
+        for placement in &result.placements {
+            assert_eq!(placement.scale, result.scale, "{packer:?}");
+        }
+    }
+}
+
+#[test]
+fn packed_windows_do_not_overlap() {
+    let windows = windows();
+    for packer in PackerKind::ALL {
+        let bin_pack2 = BinPack2 {
+            packer,
+            ..Default::default()
+        };
+        let result = bin_pack2.pack(&windows, &output().into(), &LayoutOptions::default());
+        common::assert_no_overlap(&format!("{packer:?}"), &windows, &result.placements);
+    }
+}
+
+#[test]
+fn bisection_finds_the_largest_scale() {
+    let (windows, options) = squares();
+    let area = Rect::new(0.0, 0.0, 1000.0, 1000.0).into();
+    for packer in PackerKind::ALL {
+        let bin_pack2 = BinPack2 {
+            packer,
+            ..Default::default()
+        };
+        let result = bin_pack2.pack(&windows, &area, &options);
+        assert!(result.attempts > 1, "{packer:?} {result:?}");
+        assert!(
+            result.attempts <= bin_pack2.max_attempts,
+            "{packer:?} {result:?}"
+        );
+        assert!(result.scale <= 0.5, "{packer:?} {result:?}");
+        assert!(
+            result.scale >= 0.5 - 2.0 * bin_pack2.precision,
+            "{packer:?} {result:?}"
+        );
+        common::assert_no_overlap(&format!("{packer:?}"), &windows, &result.placements);
+    }
+}
+
+#[test]
+fn failed_search_falls_back_to_expose() {
+    let (windows, options) = squares();
+    let area = Rect::new(0.0, 0.0, 1000.0, 1000.0).into();
+    for packer in PackerKind::ALL {
+        // only the packing at the upper bound is tried, and it fails
+        let bin_pack2 = BinPack2 {
+            packer,
+            max_attempts: 1,
+            ..Default::default()
+        };
+        let result = bin_pack2.pack(&windows, &area, &options);
+        assert_eq!(result.attempts, 1, "{packer:?}");
+        assert_eq!(result.placements.len(), windows.len(), "{packer:?}");
+        for placement in &result.placements {
+            assert!(placement.scale >= result.scale, "{packer:?} {placement:?}");
+        }
+        common::assert_no_overlap(&format!("{packer:?}"), &windows, &result.placements);
+    }
+}
+
+#[test]
+fn comparison_keeps_the_largest_scale() {
+    let narrow = common::windows(&[
+        (300.0, 900.0),
+        (900.0, 400.0),
+        (500.0, 500.0),
+        (1000.0, 300.0),
+        (200.0, 800.0),
+        (700.0, 600.0),
+        (120.0, 90.0),
+    ]);
+    let area = output().into();
+    let options = LayoutOptions::default();
+    for windows in [windows(), narrow] {
+        let compared = BinPack2 {
+            compare: true,
+            ..Default::default()
+        }
+        .pack(&windows, &area, &options);
+        for packer in PackerKind::ALL {
+            let single = BinPack2 {
+                packer,
+                ..Default::default()
+            }
+            .pack(&windows, &area, &options);
+            assert!(compared.scale >= single.scale, "{packer:?} {single:?}");
+        }
+        let name = format!("compare {:?}", compared.packer);
+        assert_eq!(compared.placements.len(), windows.len(), "{name}");
+        common::assert_no_overlap(&name, &windows, &compared.placements);
+        common::assert_not_blocked(&name, &windows, &compared.placements, &area);
+    }
+}
+
+#[test]
+fn packed_windows_are_centered_in_the_output() {
+    let windows = windows();
+    let output = output();
+    let options = LayoutOptions {
+        caption_height: 24.0,
+        ..Default::default()
+    };
+    for packer in PackerKind::ALL {
+        for balance in [false, true] {
+            let bin_pack2 = BinPack2 {
+                packer,
+                balance,
+                ..Default::default()
+            };
+            let result = bin_pack2.pack(&windows, &output.into(), &options);
+            let slots: Vec<Rect> = result
+                .placements
+                .iter()
+                .map(|placement| common::slot(&windows, placement, options.caption_height, 0.0))
+                .collect();
+            let left = slots.iter().map(|r| r.x).fold(f32::MAX, f32::min);
+            let top = slots.iter().map(|r| r.y).fold(f32::MAX, f32::min);
+            let right = slots.iter().map(|r| r.x + r.width).fold(f32::MIN, f32::max);
+            let bottom = slots
+                .iter()
+                .map(|r| r.y + r.height)
+                .fold(f32::MIN, f32::max);
+            let name = format!("{packer:?} balance {balance}");
+            let (center_x, center_y) = (
+                output.x + output.width * 0.5,
+                output.y + output.height * 0.5,
+            );
+            assert!(
+                ((left + right) * 0.5 - center_x).abs() < common::EPSILON,
+                "{name} {left} {right}"
+            );
+            assert!(
+                ((top + bottom) * 0.5 - center_y).abs() < common::EPSILON,
+                "{name} {top} {bottom}"
+            );
+        }
+    }
+}
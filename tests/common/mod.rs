@@ -0,0 +1,91 @@
+//! Fixtures and checks shared by the integration tests.
+
+// each test crate only uses some of them
+#![allow(dead_code)]
+
+use expose_layers::{
+    layout::{LayoutArea, Placement, Rect},
+    window::WindowGeometry,
+};
+
+/// Tolerance for rounding errors, in pixels.
+pub const EPSILON: f32 = 0.01;
+
+/// Window `id` at scale 1, the windows after it further down and right.
+pub fn window(id: usize, width: f32, height: f32) -> WindowGeometry {
+    WindowGeometry {
+        id,
+        x: id as f32 * 150.0,
+        y: id as f32 * 90.0,
+        width,
+        height,
+        scale: 1.0,
+    }
+}
+
+/// Windows of the given sizes, numbered from 0.
+pub fn windows(sizes: &[(f32, f32)]) -> Vec<WindowGeometry> {
+    sizes
+        .iter()
+        .enumerate()
+        .map(|(id, &(width, height))| window(id, width, height))
+        .collect()
+}
+
+/// Rect of the thumbnail of `placement` with `caption_height` below it,
+/// relative to its page of `page_width`.
+pub fn slot(
+    windows: &[WindowGeometry],
+    placement: &Placement,
+    caption_height: f32,
+    page_width: f32,
+) -> Rect {
+    let window = windows
+        .iter()
+        .find(|window| window.id == placement.id)
+        .expect("placement of an unknown window");
+    Rect::new(
+        placement.x - page_width * placement.page as f32,
+        placement.y,
+        window.width * placement.scale,
+        window.height * placement.scale + caption_height,
+    )
+}
+
+/// Checks the `slots` of `placements` on the same page are at least `gap`
+/// apart, don't overlap for a gap of 0.
+pub fn assert_apart(name: &str, placements: &[Placement], slots: &[Rect], gap: f32) {
+    for (i, a) in slots.iter().enumerate() {
+        for (j, b) in slots.iter().enumerate().skip(i + 1) {
+            if placements[i].page != placements[j].page {
+                continue;
+            }
+            let gap_x = a.x.max(b.x) - (a.x + a.width).min(b.x + b.width);
+            let gap_y = a.y.max(b.y) - (a.y + a.height).min(b.y + b.height);
+            assert!(gap_x.max(gap_y) >= gap, "{name} {a:?} {b:?}");
+        }
+    }
+}
+
+/// Checks the thumbnails on the same page don't overlap.
+pub fn assert_no_overlap(name: &str, windows: &[WindowGeometry], placements: &[Placement]) {
+    let slots: Vec<Rect> = placements
+        .iter()
+        .map(|placement| slot(windows, placement, 0.0, 0.0))
+        .collect();
+    assert_apart(name, placements, &slots, 0.0);
+}
+
+/// Checks no thumbnail is outside the usable part of `area` or crosses its
+/// reserved rectangles.
+pub fn assert_not_blocked(
+    name: &str,
+    windows: &[WindowGeometry],
+    placements: &[Placement],
+    area: &LayoutArea,
+) {
+    for placement in placements {
+        let rect = slot(windows, placement, 0.0, area.rect.width);
+        assert!(!area.is_blocked(rect), "{name} {rect:?}");
+    }
+}
@@ -0,0 +1,34 @@
+mod common;
+
+use expose_layers::{
+    gesture::Gesture,
+    layout::{Expose, LayoutArea, LayoutOptions, LayoutStrategy, Rect},
+};
+
+#[test]
+fn placements_only_depend_on_the_progress() {
+    let windows = common::windows(&[(800.0, 600.0), (1200.0, 800.0), (600.0, 900.0)]);
+    let area = LayoutArea::new(Rect::new(0.0, 0.0, 2560.0, 1440.0));
+    let target = Expose::default().layout(&windows, &area, &LayoutOptions::default());
+
+    let mut direct = Gesture::begin(&windows, &target);
+    let expected = direct.update(0.3);
+    let paths: [&[f32]; 3] = [&[0.8, 0.3], &[1.7, 0.6, -0.4, 0.3], &[0.1, 0.2, 0.3]];
+    for path in paths {
+        let mut gesture = Gesture::begin(&windows, &target);
+        let mut placements = Vec::new();
+        for &progress in path {
+            placements = gesture.update(progress);
+        }
+        assert_eq!(gesture.progress(), 0.3, "{path:?}");
+        assert_eq!(placements, expected, "{path:?}");
+    }
+
+    // back at the start the windows are where they were
+    let mut gesture = Gesture::begin(&windows, &target);
+    gesture.update(0.7);
+    for (placement, window) in gesture.cancel().iter().zip(&windows) {
+        assert_eq!((placement.x, placement.y), (window.x, window.y));
+        assert_eq!(placement.scale, window.scale);
+    }
+}
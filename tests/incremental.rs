@@ -0,0 +1,99 @@
+mod common;
+
+use expose_layers::{
+    layout::{
+        BinPack2, Expose, Incremental, LayoutArea, LayoutOptions, LayoutStrategy, Placement, Rect,
+    },
+    window::WindowGeometry,
+};
+
+/// Distance, in pixels, unaffected thumbnails may move by.
+const THRESHOLD: f32 = 1.0;
+
+fn windows() -> Vec<WindowGeometry> {
+    common::windows(&[
+        (800.0, 600.0),
+        (1200.0, 800.0),
+        (600.0, 900.0),
+        (900.0, 500.0),
+        (700.0, 700.0),
+    ])
+}
+
+fn area() -> LayoutArea {
+    LayoutArea::new(Rect::new(0.0, 0.0, 2560.0, 1440.0))
+}
+
+fn distance(a: &Placement, b: &Placement) -> f32 {
+    ((a.x - b.x).powi(2) + (a.y - b.y).powi(2)).sqrt()
+}
+
+fn assert_unaffected_stay(before: &[Placement], after: &[Placement], changed: usize) {
+    for placement in before.iter().filter(|placement| placement.id != changed) {
+        let moved = after
+            .iter()
+            .find(|other| other.id == placement.id)
+            .expect("window left out");
+        assert!(
+            distance(placement, moved) < THRESHOLD,
+            "{placement:?} {moved:?}"
+        );
+        assert!((placement.scale - moved.scale).abs() < 0.001);
+    }
+}
+
+#[test]
+fn inserting_keeps_other_windows_in_place() {
+    let options = LayoutOptions::default();
+    let windows = windows();
+    let strategies: [(&str, &dyn LayoutStrategy); 2] = [
+        ("expose", &Expose::default()),
+        ("bin_pack2", &BinPack2::default()),
+    ];
+    for (name, strategy) in strategies {
+        let before = strategy.layout(&windows, &area(), &options);
+        let new_window = common::window(10, 400.0, 300.0);
+        let incremental = Incremental { strategy };
+        let after = incremental.insert(&windows, &before, &new_window, &area(), &options);
+
+        assert_eq!(after.len(), windows.len() + 1, "{name}");
+        assert_unaffected_stay(&before, &after, new_window.id);
+        let mut all = windows.clone();
+        all.push(new_window);
+        common::assert_no_overlap(name, &all, &after);
+    }
+}
+
+#[test]
+fn inserting_without_room_lays_out_every_window() {
+    let options = LayoutOptions::default();
+    let windows = windows();
+    let strategy = BinPack2::default();
+    let before = strategy.layout(&windows, &area(), &options);
+    let new_window = common::window(10, 2400.0, 1400.0);
+    let incremental = Incremental {
+        strategy: &strategy,
+    };
+    let after = incremental.insert(&windows, &before, &new_window, &area(), &options);
+
+    assert_eq!(after.len(), windows.len() + 1);
+    let mut all = windows.clone();
+    all.push(new_window);
+    common::assert_no_overlap("bin_pack2", &all, &after);
+}
+
+#[test]
+fn removing_keeps_other_windows_in_place() {
+    let options = LayoutOptions::default();
+    let windows = windows();
+    let strategy = Expose::default();
+    let before = strategy.layout(&windows, &area(), &options);
+    let incremental = Incremental {
+        strategy: &strategy,
+    };
+    let after = incremental.remove(&before, 2);
+
+    assert_eq!(after.len(), windows.len() - 1);
+    assert!(after.iter().all(|placement| placement.id != 2));
+    assert_unaffected_stay(&before, &after, 2);
+}
@@ -0,0 +1,30 @@
+#![cfg(feature = "layers")]
+
+use expose_layers::window::{window_geometry, window_size};
+use layers::{prelude::*, types::Size};
+
+#[test]
+fn percent_sizes_are_resolved_by_the_engine() {
+    let engine = LayersEngine::new(1000.0, 800.0);
+    let root = engine.new_layer();
+    root.set_size(Size::points(1000.0, 800.0), None);
+    engine.scene_add_layer(root.clone());
+
+    let window = engine.new_layer();
+    window.set_size(
+        Size {
+            width: taffy::Dimension::Percent(0.5),
+            height: taffy::Dimension::Percent(0.25),
+        },
+        None,
+    );
+    window.set_position((100.0, 50.0), None);
+    engine.scene_add_layer(window.clone());
+    engine.update(0.0);
+
+    assert_eq!(window_size(&window), (500.0, 200.0));
+    let geometry = window_geometry(&window);
+    assert_eq!((geometry.x, geometry.y), (100.0, 50.0));
+    assert_eq!((geometry.width, geometry.height), (500.0, 200.0));
+    assert_eq!(geometry.scale, 1.0);
+}
@@ -0,0 +1,90 @@
+mod common;
+
+use expose_layers::{
+    layout::{
+        BinPack, BinPack2, Expose, Insets, JustifiedRows, LayoutArea, LayoutOptions,
+        LayoutStrategy, Natural, PackerKind, Rect,
+    },
+    window::WindowGeometry,
+};
+
+fn windows() -> Vec<WindowGeometry> {
+    common::windows(&[
+        (300.0, 900.0),
+        (900.0, 400.0),
+        (500.0, 500.0),
+        (1000.0, 300.0),
+        (200.0, 800.0),
+        (700.0, 600.0),
+        (400.0, 1000.0),
+        (800.0, 350.0),
+    ])
+}
+
+/// A top panel, a dock at the bottom and a notch at the top center.
+fn area() -> LayoutArea {
+    LayoutArea {
+        rect: Rect::new(0.0, 0.0, 1920.0, 1080.0),
+        insets: Insets {
+            top: 32.0,
+            ..Default::default()
+        },
+        reserved: vec![
+            Rect::new(560.0, 980.0, 800.0, 100.0),
+            Rect::new(860.0, 0.0, 200.0, 80.0),
+        ],
+    }
+}
+
+fn assert_avoids_reserved(name: &str, strategy: &dyn LayoutStrategy) {
+    let windows = windows();
+    let area = area();
+    let placements = strategy.layout(&windows, &area, &LayoutOptions::default());
+    assert_eq!(placements.len(), windows.len(), "{name}");
+    common::assert_not_blocked(name, &windows, &placements, &area);
+}
+
+#[test]
+fn strategies_avoid_reserved_space() {
+    assert_avoids_reserved("expose", &Expose::default());
+    assert_avoids_reserved("bin_pack", &BinPack);
+    assert_avoids_reserved("rows", &JustifiedRows);
+    assert_avoids_reserved("natural", &Natural::default());
+    for packer in PackerKind::ALL {
+        for balance in [false, true] {
+            let bin_pack2 = BinPack2 {
+                packer,
+                balance,
+                ..Default::default()
+            };
+            assert_avoids_reserved(&format!("bin_pack2 {packer:?}"), &bin_pack2);
+        }
+    }
+}
+
+#[test]
+fn bin_treats_reserved_space_as_occupied() {
+    let area = area();
+    let free: f32 = area
+        .free_rects()
+        .iter()
+        .map(|rect| rect.width * rect.height)
+        .sum();
+    let usable = area.usable();
+    let reserved = 800.0 * 100.0 + 200.0 * (80.0 - 32.0);
+    assert!((usable.width * usable.height - reserved - free).abs() < 1.0);
+}
+
+#[test]
+fn bin_pack2_packs_around_reserved_space() {
+    let windows = windows();
+    let result = BinPack2::default().pack(&windows, &area(), &LayoutOptions::default());
+    assert_eq!(result.packer, PackerKind::Bin);
+    common::assert_not_blocked("bin_pack2", &windows, &result.placements, &area());
+    let compared = BinPack2 {
+        compare: true,
+        ..Default::default()
+    }
+    .pack(&windows, &area(), &LayoutOptions::default());
+    assert_eq!(compared.packer, PackerKind::Bin);
+}
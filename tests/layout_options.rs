@@ -0,0 +1,149 @@
+mod common;
+
+use expose_layers::{
+    layout::{
+        BinPack, BinPack2, Expose, JustifiedRows, LayoutArea, LayoutOptions, LayoutStrategy,
+        Natural, PackerKind, Rect,
+    },
+    window::WindowGeometry,
+};
+
+use common::EPSILON;
+
+fn windows() -> Vec<WindowGeometry> {
+    common::windows(&[
+        (300.0, 900.0),
+        (900.0, 400.0),
+        (500.0, 500.0),
+        (1000.0, 300.0),
+        (200.0, 800.0),
+        (700.0, 600.0),
+        (120.0, 90.0),
+    ])
+}
+
+fn options() -> LayoutOptions {
+    LayoutOptions {
+        margin: 40.0,
+        spacing: 30.0,
+        caption_height: 24.0,
+        max_scale: 1.5,
+    }
+}
+
+/// Checks the slots, captions included, stay within the margin, are at least
+/// `spacing` apart and are not scaled above `max_scale`.
+fn assert_follows_options(name: &str, strategy: &dyn LayoutStrategy) {
+    let windows = windows();
+    let output = Rect::new(0.0, 0.0, 2560.0, 1440.0);
+    let options = options();
+    let placements = strategy.layout(&windows, &LayoutArea::new(output), &options);
+    assert_eq!(placements.len(), windows.len(), "{name}");
+
+    let slots: Vec<Rect> = placements
+        .iter()
+        .map(|placement| {
+            assert!(placement.scale <= options.max_scale, "{name} {placement:?}");
+            common::slot(&windows, placement, options.caption_height, output.width)
+        })
+        .collect();
+    let margin = options.margin - EPSILON;
+    for slot in &slots {
+        assert!(slot.x >= output.x + margin, "{name} {slot:?}");
+        assert!(slot.y >= output.y + margin, "{name} {slot:?}");
+        assert!(
+            slot.x + slot.width <= output.width - margin,
+            "{name} {slot:?}"
+        );
+        assert!(
+            slot.y + slot.height <= output.height - margin,
+            "{name} {slot:?}"
+        );
+    }
+    common::assert_apart(name, &placements, &slots, options.spacing - EPSILON);
+}
+
+#[test]
+fn strategies_follow_the_options() {
+    assert_follows_options("expose", &Expose::default());
+    assert_follows_options("bin_pack", &BinPack);
+    assert_follows_options("rows", &JustifiedRows);
+    assert_follows_options("natural", &Natural::default());
+    for packer in PackerKind::ALL {
+        for balance in [false, true] {
+            let bin_pack2 = BinPack2 {
+                packer,
+                balance,
+                ..Default::default()
+            };
+            assert_follows_options(&format!("bin_pack2 {packer:?}"), &bin_pack2);
+        }
+    }
+}
+
+#[test]
+fn natural_captions_stay_off_reserved_space() {
+    let windows: Vec<WindowGeometry> = (0..17)
+        .map(|id| common::window(id, 600.0 + id as f32 * 20.0, 400.0))
+        .collect();
+    let area = LayoutArea {
+        rect: Rect::new(0.0, 0.0, 1920.0, 1080.0),
+        insets: Default::default(),
+        reserved: vec![Rect::new(560.0, 980.0, 800.0, 100.0)],
+    };
+    let options = LayoutOptions {
+        caption_height: 30.0,
+        ..Default::default()
+    };
+    let placements = Natural::default().layout(&windows, &area, &options);
+    for placement in &placements {
+        let slot = common::slot(&windows, placement, options.caption_height, area.rect.width);
+        assert!(!area.is_blocked(slot), "{slot:?}");
+    }
+}
+
+#[test]
+fn small_windows_are_not_upscaled() {
+    let windows: Vec<WindowGeometry> = windows()
+        .into_iter()
+        .map(|window| WindowGeometry {
+            width: window.width * 0.2,
+            height: window.height * 0.2,
+            ..window
+        })
+        .collect();
+    let area = LayoutArea::new(Rect::new(0.0, 0.0, 2560.0, 1440.0));
+    let strategies: [(&str, &dyn LayoutStrategy); 5] = [
+        ("expose", &Expose::default()),
+        ("bin_pack", &BinPack),
+        ("bin_pack2", &BinPack2::default()),
+        ("rows", &JustifiedRows),
+        ("natural", &Natural::default()),
+    ];
+    for (name, strategy) in strategies {
+        for placement in strategy.layout(&windows, &area, &LayoutOptions::default()) {
+            assert!(placement.scale <= 1.0, "{name} {placement:?}");
+        }
+    }
+}
+
+#[test]
+fn expose_centers_undersized_windows_in_their_cell() {
+    let window = WindowGeometry {
+        id: 0,
+        x: 0.0,
+        y: 0.0,
+        width: 400.0,
+        height: 300.0,
+        scale: 1.0,
+    };
+    let output = Rect::new(0.0, 0.0, 2560.0, 1440.0);
+    let placements = Expose::default().layout(
+        &[window],
+        &LayoutArea::new(output),
+        &LayoutOptions::default(),
+    );
+    assert_eq!(placements[0].scale, 1.0);
+    assert_eq!(placements[0].x, (output.width - window.width) * 0.5);
+    assert_eq!(placements[0].y, (output.height - window.height) * 0.5);
+}
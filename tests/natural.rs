@@ -0,0 +1,77 @@
+mod common;
+
+use expose_layers::{
+    layout::{LayoutArea, LayoutOptions, LayoutStrategy, Natural, Placement, Rect},
+    window::WindowGeometry,
+};
+
+fn area() -> LayoutArea {
+    LayoutArea::new(Rect::new(0.0, 0.0, 2560.0, 1440.0))
+}
+
+/// Distance, in pixels, thumbnails may move by when laid out again.
+const THRESHOLD: f32 = 1.0;
+
+/// `count` windows of 800x600, each `step` further right than the previous.
+fn piled(count: usize, step: f32) -> Vec<WindowGeometry> {
+    (0..count)
+        .map(|id| WindowGeometry {
+            x: 500.0 + id as f32 * step,
+            y: 300.0 + id as f32 * step * 0.5,
+            ..common::window(id, 800.0, 600.0)
+        })
+        .collect()
+}
+
+/// Windows at the geometry of their thumbnails.
+fn thumbnails(windows: &[WindowGeometry], placements: &[Placement]) -> Vec<WindowGeometry> {
+    windows
+        .iter()
+        .zip(placements)
+        .map(|(window, placement)| WindowGeometry {
+            x: placement.x,
+            y: placement.y,
+            scale: placement.scale,
+            ..*window
+        })
+        .collect()
+}
+
+#[test]
+fn piled_windows_spread_in_both_directions() {
+    let windows = piled(20, 1.0);
+    let placements = Natural::default().layout(&windows, &area(), &LayoutOptions::default());
+    common::assert_no_overlap("natural", &windows, &placements);
+    // pushed along a single axis, they would end up in a line at 0.07
+    for placement in &placements {
+        assert!(placement.scale > 0.25, "{placement:?}");
+    }
+}
+
+#[test]
+fn stacked_windows_never_overlap() {
+    let windows = piled(30, 0.0);
+    let placements = Natural::default().layout(&windows, &area(), &LayoutOptions::default());
+    assert_eq!(placements.len(), windows.len());
+    common::assert_no_overlap("natural", &windows, &placements);
+}
+
+#[test]
+fn laying_out_again_keeps_the_thumbnails() {
+    let windows = common::windows(&[
+        (300.0, 900.0),
+        (900.0, 400.0),
+        (500.0, 500.0),
+        (1000.0, 300.0),
+        (200.0, 800.0),
+        (700.0, 600.0),
+    ]);
+    let natural = Natural::default();
+    let options = LayoutOptions::default();
+    let first = natural.layout(&windows, &area(), &options);
+    let again = natural.layout(&thumbnails(&windows, &first), &area(), &options);
+    for (a, b) in first.iter().zip(&again) {
+        assert!((a.x - b.x).hypot(a.y - b.y) < THRESHOLD, "{a:?} {b:?}");
+        assert!((a.scale - b.scale).abs() < 0.001, "{a:?} {b:?}");
+    }
+}
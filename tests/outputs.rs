@@ -0,0 +1,107 @@
+mod common;
+
+use expose_layers::{
+    layout::{page_count, BinPack, LayoutOptions, MultiOutput, Output, OutputAssignment, Rect},
+    window::WindowGeometry,
+};
+
+#[test]
+fn pages_are_as_wide_as_all_the_outputs() {
+    let outputs = [
+        Output::new(Rect::new(0.0, 0.0, 1200.0, 1080.0), 1.0),
+        Output::new(Rect::new(1200.0, 0.0, 720.0, 1080.0), 2.0),
+    ];
+    let windows: Vec<_> = (0..24)
+        .map(|id| common::window(id, 1400.0, 1000.0))
+        .collect();
+    let multi_output = MultiOutput {
+        strategy: &BinPack,
+        assignment: OutputAssignment::Balance,
+    };
+    let options = LayoutOptions::default();
+    let placements = multi_output.layout_outputs(&windows, &outputs, &options);
+    assert!(page_count(&placements) > 1);
+
+    let assigned = multi_output.assign(&windows, &outputs);
+    for placement in &placements {
+        let output = outputs[assigned[placement.id]].area.rect;
+        let slot = common::slot(&windows, placement, 0.0, 1920.0);
+        assert!(slot.x >= output.x, "{placement:?} {output:?}");
+        assert!(
+            slot.x + slot.width <= output.x + output.width + common::EPSILON,
+            "{placement:?} {output:?}"
+        );
+    }
+}
+
+/// A 2:1 output with a square one on its right.
+fn outputs() -> [Output; 2] {
+    [
+        Output::new(Rect::new(0.0, 0.0, 2000.0, 1000.0), 1.0),
+        Output::new(Rect::new(2000.0, 0.0, 1000.0, 1000.0), 1.0),
+    ]
+}
+
+fn at(id: usize, x: f32, y: f32, width: f32, height: f32) -> WindowGeometry {
+    WindowGeometry {
+        id,
+        x,
+        y,
+        width,
+        height,
+        scale: 1.0,
+    }
+}
+
+#[test]
+fn windows_go_to_the_output_they_overlap_most() {
+    let windows = [
+        // straddling, mostly on the left output
+        at(0, 1500.0, 100.0, 800.0, 600.0),
+        // straddling, mostly on the right output
+        at(1, 1800.0, 100.0, 800.0, 600.0),
+        // scaled down to fit the right output only
+        WindowGeometry {
+            scale: 0.25,
+            ..at(2, 2100.0, 100.0, 1600.0, 1200.0)
+        },
+        // off screen, nearest to the left output
+        at(3, -3000.0, 200.0, 800.0, 600.0),
+        // off screen, under the right output
+        at(4, 2300.0, 1800.0, 400.0, 300.0),
+    ];
+    let multi_output = MultiOutput {
+        strategy: &BinPack,
+        assignment: OutputAssignment::Overlap,
+    };
+    assert_eq!(multi_output.assign(&windows, &outputs()), [0, 1, 1, 0, 1]);
+}
+
+#[test]
+fn balance_fills_outputs_in_proportion_to_their_area() {
+    let windows: Vec<_> = (0..30)
+        .map(|id| at(id, 100.0 + id as f32 * 10.0, 100.0, 300.0, 200.0))
+        .collect();
+    let outputs = outputs();
+    let multi_output = MultiOutput {
+        strategy: &BinPack,
+        assignment: OutputAssignment::Balance,
+    };
+    let assigned = multi_output.assign(&windows, &outputs);
+
+    // every window starts on the left output, two thirds of the area stay
+    let mut filled = [0.0; 2];
+    for (window, output) in windows.iter().zip(&assigned) {
+        filled[*output] += window.area();
+    }
+    let total: f32 = filled.iter().sum();
+    let window_share = 300.0 * 200.0 / total;
+    for (output, filled) in outputs.iter().zip(filled) {
+        let rect = output.area.rect;
+        let share = rect.width * rect.height / (3000.0 * 1000.0);
+        assert!(
+            (filled / total - share).abs() <= window_share,
+            "{assigned:?}"
+        );
+    }
+}
@@ -0,0 +1,89 @@
+use expose_layers::{layout::Rect, packer::Bin};
+
+fn overlaps(a: &Rect, b: &Rect) -> bool {
+    a.x < b.x + b.width && b.x < a.x + a.width && a.y < b.y + b.height && b.y < a.y + a.height
+}
+
+fn assert_disjoint(name: &str, rects: &[Rect]) {
+    for (i, a) in rects.iter().enumerate() {
+        for b in &rects[i + 1..] {
+            assert!(!overlaps(a, b), "{name} {a:?} {b:?}");
+        }
+    }
+}
+
+#[test]
+fn added_items_do_not_overlap() {
+    let mut bin = Bin::new(1000.0, 800.0);
+    bin.reserve(Rect::new(300.0, 700.0, 400.0, 100.0));
+    let sizes = [
+        (310.5, 220.25),
+        (120.0, 480.0),
+        (400.0, 90.5),
+        (250.75, 250.75),
+        (90.0, 130.0),
+        (500.0, 120.0),
+        (75.5, 60.0),
+        (200.0, 310.0),
+    ];
+    for (id, (width, height)) in sizes.into_iter().enumerate() {
+        let rect = bin.add(id, width, height).expect("item left out");
+        assert_eq!((rect.width, rect.height), (width, height));
+        assert!(
+            rect.x >= 0.0 && rect.x + rect.width <= bin.width(),
+            "{rect:?}"
+        );
+        assert!(
+            rect.y >= 0.0 && rect.y + rect.height <= bin.height(),
+            "{rect:?}"
+        );
+    }
+
+    let placed: Vec<Rect> = bin.placed().iter().map(|(_, rect)| *rect).collect();
+    assert_eq!(placed.len(), sizes.len());
+    let mut occupied = placed.clone();
+    occupied.push(Rect::new(300.0, 700.0, 400.0, 100.0));
+    assert_disjoint("placed", &occupied);
+    assert_disjoint("free", bin.free());
+    for free in bin.free() {
+        for rect in &occupied {
+            assert!(!overlaps(free, rect), "{free:?} {rect:?}");
+        }
+    }
+}
+
+#[test]
+fn reserving_splits_the_free_rects() {
+    let mut bin = Bin::new(100.0, 100.0);
+    bin.reserve(Rect::new(40.0, 40.0, 20.0, 20.0));
+    let mut free = bin.free().to_vec();
+    free.sort_by(|a, b| (a.y, a.x).partial_cmp(&(b.y, b.x)).unwrap());
+    assert_eq!(
+        free,
+        [
+            Rect::new(0.0, 0.0, 100.0, 40.0),
+            Rect::new(0.0, 40.0, 40.0, 20.0),
+            Rect::new(60.0, 40.0, 40.0, 20.0),
+            Rect::new(0.0, 60.0, 100.0, 40.0),
+        ]
+    );
+    assert!(bin.can_fit(100.0, 40.0));
+    assert!(!bin.can_fit(100.0, 41.0));
+
+    // against a corner only the parts beside and under it are left
+    let mut bin = Bin::new(100.0, 100.0);
+    bin.reserve(Rect::new(0.0, 0.0, 20.0, 30.0));
+    let mut free = bin.free().to_vec();
+    free.sort_by(|a, b| (a.y, a.x).partial_cmp(&(b.y, b.x)).unwrap());
+    assert_eq!(
+        free,
+        [
+            Rect::new(20.0, 0.0, 80.0, 30.0),
+            Rect::new(0.0, 30.0, 100.0, 70.0),
+        ]
+    );
+
+    // clearing drops the reservations
+    bin.clear();
+    assert_eq!(bin.free(), [Rect::new(0.0, 0.0, 100.0, 100.0)]);
+}
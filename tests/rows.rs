@@ -0,0 +1,68 @@
+mod common;
+
+use expose_layers::{
+    layout::{JustifiedRows, LayoutArea, LayoutOptions, LayoutStrategy, Placement, Rect},
+    window::WindowGeometry,
+};
+
+use common::EPSILON;
+
+/// Placements grouped by the row they are on, from top to bottom.
+fn rows(placements: &[Placement]) -> Vec<Vec<Placement>> {
+    let mut rows: Vec<Vec<Placement>> = Vec::new();
+    for placement in placements {
+        match rows.last_mut() {
+            Some(row) if (row[0].y - placement.y).abs() < EPSILON => row.push(*placement),
+            _ => rows.push(vec![*placement]),
+        }
+    }
+    rows
+}
+
+#[test]
+fn rows_are_justified() {
+    let windows: Vec<WindowGeometry> = common::windows(&[
+        (1600.0, 1200.0),
+        (2400.0, 1000.0),
+        (1200.0, 1600.0),
+        (2000.0, 1100.0),
+        (1500.0, 1500.0),
+        (2560.0, 1080.0),
+        (1400.0, 1800.0),
+        (1900.0, 1200.0),
+        (1700.0, 1000.0),
+    ]);
+    // tall enough for the rows to be bound by the width
+    let output = Rect::new(40.0, 25.0, 2560.0, 3200.0);
+    let options = LayoutOptions {
+        caption_height: 24.0,
+        ..Default::default()
+    };
+    let placements = JustifiedRows.layout(&windows, &LayoutArea::new(output), &options);
+    assert_eq!(placements.len(), windows.len());
+
+    let rows = rows(&placements);
+    assert!(rows.len() > 1, "{rows:?}");
+    let left = output.x + options.margin;
+    let right = output.x + output.width - options.margin;
+    for (index, row) in rows.iter().enumerate() {
+        let slots: Vec<Rect> = row
+            .iter()
+            .map(|placement| common::slot(&windows, placement, 0.0, 0.0))
+            .collect();
+        for slot in &slots {
+            assert!(
+                (slot.height - slots[0].height).abs() < EPSILON,
+                "row {index} {slots:?}"
+            );
+        }
+        if index + 1 < rows.len() {
+            let last = slots.last().unwrap();
+            assert!((slots[0].x - left).abs() < EPSILON, "row {index} {slots:?}");
+            assert!(
+                (last.x + last.width - right).abs() < EPSILON,
+                "row {index} {slots:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,83 @@
+mod common;
+
+use expose_layers::layout::{
+    BinPack2, Expose, Insets, JustifiedRows, LayoutArea, LayoutOptions, LayoutStrategy, Rect,
+    Scrollable,
+};
+
+#[test]
+fn content_ends_below_the_last_captions() {
+    let windows: Vec<_> = (0..40)
+        .map(|id| common::window(id, 1200.0, 900.0))
+        .collect();
+    let area = LayoutArea {
+        rect: Rect::new(0.0, 0.0, 1920.0, 1080.0),
+        insets: Insets {
+            bottom: 60.0,
+            ..Default::default()
+        },
+        reserved: Vec::new(),
+    };
+    let options = LayoutOptions {
+        caption_height: 30.0,
+        ..Default::default()
+    };
+    let scrollable = Scrollable {
+        strategy: &Expose::default(),
+        min_thumbnail_size: 300.0,
+    };
+    let layout = scrollable.layout_scrollable(&windows, &area, &options);
+    assert!(layout.content_height > area.rect.height);
+    for placement in &layout.placements {
+        let slot = common::slot(&windows, placement, options.caption_height, area.rect.width);
+        let bottom = slot.y + slot.height + options.margin + area.insets.bottom;
+        assert!(
+            bottom <= area.rect.y + layout.content_height + common::EPSILON,
+            "{slot:?} {}",
+            layout.content_height
+        );
+    }
+}
+
+#[test]
+fn docks_stay_at_the_bottom_of_the_content() {
+    let windows: Vec<_> = (0..40)
+        .map(|id| common::window(id, 1200.0, 900.0))
+        .collect();
+    let notch = Rect::new(860.0, 0.0, 200.0, 80.0);
+    let dock = Rect::new(560.0, 980.0, 800.0, 100.0);
+    let area = LayoutArea {
+        rect: Rect::new(0.0, 0.0, 1920.0, 1080.0),
+        insets: Default::default(),
+        reserved: vec![notch, dock],
+    };
+    let options = LayoutOptions {
+        caption_height: 30.0,
+        ..Default::default()
+    };
+    let strategies: [(&str, &dyn LayoutStrategy); 3] = [
+        ("expose", &Expose::default()),
+        ("bin_pack2", &BinPack2::default()),
+        ("rows", &JustifiedRows),
+    ];
+    for (name, strategy) in strategies {
+        let scrollable = Scrollable {
+            strategy,
+            min_thumbnail_size: 300.0,
+        };
+        let layout = scrollable.layout_scrollable(&windows, &area, &options);
+        assert!(layout.content_height > area.rect.height, "{name}");
+
+        let content = area.with_height(layout.content_height);
+        assert_eq!(content.reserved[0], notch, "{name}");
+        assert_eq!(
+            content.reserved[1].y + dock.height,
+            layout.content_height,
+            "{name}"
+        );
+        for placement in &layout.placements {
+            let slot = common::slot(&windows, placement, options.caption_height, area.rect.width);
+            assert!(!content.is_blocked(slot), "{name} {slot:?}");
+        }
+    }
+}
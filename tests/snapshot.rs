@@ -0,0 +1,79 @@
+mod common;
+
+use expose_layers::{layout::Placement, snapshot::Snapshot, window::WindowGeometry};
+
+/// The windows moved and scaled down as thumbnails.
+fn thumbnails(windows: &[WindowGeometry]) -> Vec<WindowGeometry> {
+    windows
+        .iter()
+        .map(|window| WindowGeometry {
+            x: window.x * 0.5 + 30.0,
+            y: window.y * 0.5 + 20.0,
+            scale: 0.4,
+            ..*window
+        })
+        .collect()
+}
+
+#[test]
+fn restore_returns_the_captured_geometry() {
+    let mut windows = common::windows(&[(800.0, 600.0), (1200.0, 800.0), (600.0, 900.0)]);
+    windows[1].scale = 0.75;
+    windows[2].x = 13.25;
+    let snapshot = Snapshot::capture(&windows);
+
+    let placements = snapshot.restore(&thumbnails(&windows));
+    assert_eq!(placements.len(), windows.len());
+    for (placement, window) in placements.iter().zip(&windows) {
+        assert_eq!(
+            *placement,
+            Placement::new(window.id, window.x, window.y, window.scale)
+        );
+    }
+}
+
+#[test]
+fn uncaptured_windows_stay_where_they_are() {
+    let windows = common::windows(&[(800.0, 600.0), (1200.0, 800.0)]);
+    let snapshot = Snapshot::capture(&windows);
+
+    let mut shown = thumbnails(&windows);
+    let new_window = WindowGeometry {
+        scale: 0.3,
+        ..common::window(7, 400.0, 300.0)
+    };
+    shown.push(new_window);
+    let placements = snapshot.restore(&shown);
+    assert_eq!(placements.len(), 3);
+    assert_eq!(
+        placements[2],
+        Placement::new(7, new_window.x, new_window.y, 1.0)
+    );
+}
+
+#[test]
+fn removed_windows_are_ignored() {
+    let windows = common::windows(&[(800.0, 600.0), (1200.0, 800.0), (600.0, 900.0)]);
+    let mut snapshot = Snapshot::capture(&windows);
+
+    // closed while the overview is shown
+    assert!(snapshot.remove(1).is_some());
+    assert_eq!(snapshot.get(1), None);
+    let shown: Vec<WindowGeometry> = thumbnails(&windows)
+        .into_iter()
+        .filter(|window| window.id != 1)
+        .collect();
+    let placements = snapshot.restore(&shown);
+    assert_eq!(
+        placements,
+        [
+            Placement::new(0, windows[0].x, windows[0].y, 1.0),
+            Placement::new(2, windows[2].x, windows[2].y, 1.0),
+        ]
+    );
+
+    // removed before the snapshot knew about it, or twice
+    assert_eq!(snapshot.remove(1), None);
+    assert_eq!(snapshot.remove(42), None);
+    assert_eq!(snapshot.restore(&shown), placements);
+}